@@ -1,3 +1,46 @@
+// App commands are declared so capabilities can allow them per window
+const COMMANDS: &[&str] = &[
+    "initialize",
+    "get_game_state",
+    "set_auto_lock",
+    "get_auto_lock",
+    "get_settings",
+    "update_settings",
+    "set_hotkey",
+    "pause_hotkeys",
+    "resume_hotkeys",
+    "get_hotkey_errors",
+    "set_overlay_settings",
+    "set_click_through",
+    "get_click_through",
+    "drag_hud",
+    "export_diagnostics",
+    "get_owned_agents",
+    "hover_agent",
+    "dodge_match",
+    "get_team_composition",
+    "get_player_loadout",
+    "get_all_loadouts",
+    "get_storefront",
+    "get_wallet",
+    "get_owned_skins",
+    "get_my_loadout",
+    "set_my_loadout",
+    "equip_skin",
+    "get_party",
+    "set_party_queue",
+    "set_party_open",
+    "invite_to_party",
+    "kick_party_member",
+    "leave_party",
+    "start_queue",
+    "stop_queue",
+    "get_friends",
+    "get_server_history",
+];
+
 fn main() {
-    tauri_build::build()
+    let manifest = tauri_build::AppManifest::new().commands(COMMANDS);
+    tauri_build::try_build(tauri_build::Attributes::new().app_manifest(manifest))
+        .expect("failed to run tauri-build");
 }
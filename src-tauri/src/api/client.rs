@@ -1,11 +1,19 @@
+use crate::api::requestlog::RequestSummary;
+use crate::api::scheduler::{Body, RequestScheduler};
 use crate::api::types::*;
+use crate::cache::{self, Cache};
+use crate::constants::{AGENTS, DEFAULT_AGENTS, ITEM_TYPE_AGENTS};
+use crate::lookup;
+use crate::stats;
 use base64::{engine::general_purpose::STANDARD, Engine};
+use futures_util::stream::{self, StreamExt};
 use parking_lot::RwLock;
 use reqwest::Client;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::time::Instant;
 use thiserror::Error;
 
-#[derive(Error, Debug)]
+#[derive(Error, Debug, Clone)]
 pub enum ApiError {
     #[error("Valorant not running")]
     NotRunning,
@@ -13,17 +21,28 @@ pub enum ApiError {
     RequestFailed(String),
     #[error("Parse error: {0}")]
     ParseError(String),
+    #[error("HTTP {status}: {message}")]
+    Http { status: u16, message: String },
 }
 
 pub struct ValorantAPI {
-    client: Client,
+    pub(super) client: Client,
     pub puuid: RwLock<String>,
     pub region: RwLock<String>,
     pub shard: RwLock<String>,
     local_port: RwLock<String>,
     local_auth: RwLock<String>,
     remote_headers: RwLock<HashMap<String, String>>,
+    // Throttles and coalesces everything sent to pd/glz
+    scheduler: RequestScheduler,
+    // Finished matches never change - backed by disk once the app dir is known
+    pub match_details: Cache<MatchDetailsResponse>,
+    // Id of the current act - ranks are read for it
+    season: Cache<String>,
     pub connected: RwLock<bool>,
+    // Tests point pd/glz/shared at a local mock server
+    #[cfg(test)]
+    remote_base: RwLock<Option<String>>,
 }
 
 impl ValorantAPI {
@@ -41,7 +60,13 @@ impl ValorantAPI {
             local_port: RwLock::new(String::new()),
             local_auth: RwLock::new(String::new()),
             remote_headers: RwLock::new(HashMap::new()),
+            scheduler: RequestScheduler::new(),
+            match_details: Cache::new(cache::MATCH_DETAILS_TTL)
+                .with_max_entries(cache::MATCH_DETAILS_MAX),
+            season: Cache::new(cache::SEASON_TTL),
             connected: RwLock::new(false),
+            #[cfg(test)]
+            remote_base: RwLock::new(None),
         }
     }
 
@@ -170,34 +195,102 @@ impl ValorantAPI {
         "release-09.10-shipping-18-2775386".to_string()
     }
 
-    fn glz_url(&self, endpoint: &str) -> String {
+    /// Most recent local and remote requests, oldest first
+    pub fn recent_requests(&self) -> Vec<RequestSummary> {
+        self.scheduler.log.recent()
+    }
+
+    /// Client version sent with remote requests
+    pub fn client_version(&self) -> String {
+        self.remote_headers.read().get("X-Riot-ClientVersion").cloned().unwrap_or_default()
+    }
+
+    pub(super) fn glz_url(&self, endpoint: &str) -> String {
         let region = self.region.read();
         let shard = self.shard.read();
         let glz_region = if region.to_lowercase() == "tr" { "eu" } else { &region };
-        format!("https://glz-{}-1.{}.a.pvp.net{}", glz_region, shard, endpoint)
+        self.remote_url(&format!("https://glz-{}-1.{}.a.pvp.net", glz_region, shard), endpoint)
     }
 
-    fn pd_url(&self, endpoint: &str) -> String {
+    pub(super) fn pd_url(&self, endpoint: &str) -> String {
         let shard = self.shard.read();
-        format!("https://pd.{}.a.pvp.net{}", shard, endpoint)
+        self.remote_url(&format!("https://pd.{}.a.pvp.net", shard), endpoint)
     }
 
-    async fn get_remote<T: serde::de::DeserializeOwned>(&self, url: &str) -> Option<T> {
-        let headers: HashMap<String, String> = self.remote_headers.read().clone();
-        let mut req = self.client.get(url);
-        for (k, v) in headers.iter() {
-            req = req.header(k, v);
+    pub(super) fn shared_url(&self, endpoint: &str) -> String {
+        let shard = self.shard.read();
+        self.remote_url(&format!("https://shared.{}.a.pvp.net", shard), endpoint)
+    }
+
+    /// Send remote requests to a mock server, without the per-host rate limit
+    #[cfg(test)]
+    pub(crate) fn mock_remote(&self, base: &str) {
+        *self.remote_base.write() = Some(base.to_string());
+        self.scheduler.unthrottled.store(true, std::sync::atomic::Ordering::SeqCst);
+    }
+
+    fn remote_url(&self, host: &str, endpoint: &str) -> String {
+        #[cfg(test)]
+        if let Some(base) = self.remote_base.read().as_deref() {
+            return format!("{}{}", base, endpoint);
         }
-        req.send().await.ok()?.json().await.ok()
+        format!("{}{}", host, endpoint)
+    }
+
+    /// GET the local Riot client API with basic auth
+    pub(super) async fn get_local<T: serde::de::DeserializeOwned>(&self, endpoint: &str) -> Option<T> {
+        let port = self.local_port.read().clone();
+        let auth = self.local_auth.read().clone();
+        let url = format!("https://127.0.0.1:{}{}", port, endpoint);
+
+        let started = Instant::now();
+        let resp = self.client.get(&url).header("Authorization", &auth).send().await;
+        if let Ok(parsed) = reqwest::Url::parse(&url) {
+            let status = resp.as_ref().ok().map(|r| r.status().as_u16());
+            let error = resp.as_ref().err().map(|e| e.to_string());
+            self.scheduler.log.record(&reqwest::Method::GET, &parsed, status, started, error);
+        }
+        resp.ok()?.json().await.ok()
+    }
+
+    /// GET a public endpoint (valorant-api.com) without Riot headers
+    pub(super) async fn get_public<T: serde::de::DeserializeOwned>(&self, url: &str) -> Option<T> {
+        self.client.get(url).send().await.ok()?.json().await.ok()
+    }
+
+    pub(super) async fn get_remote<T: serde::de::DeserializeOwned>(&self, url: &str) -> Option<T> {
+        self.try_get_remote(url).await.ok()
     }
 
-    async fn post_remote(&self, url: &str) -> Option<serde_json::Value> {
+    /// Like `get_remote` but keeps the error
+    pub(super) async fn try_get_remote<T: serde::de::DeserializeOwned>(&self, url: &str) -> Result<T, ApiError> {
+        self.send_remote(self.client.get(url)).await
+    }
+
+    pub(super) async fn post_remote<T: serde::de::DeserializeOwned>(&self, url: &str) -> Result<T, ApiError> {
+        let req = self.client.post(url).json(&serde_json::json!({}));
+        self.send_remote(req).await
+    }
+
+    /// Send a request with remote headers, keeping the HTTP status on failure
+    pub(super) async fn send_remote<T: serde::de::DeserializeOwned>(
+        &self,
+        req: reqwest::RequestBuilder,
+    ) -> Result<T, ApiError> {
+        let body = self.send_remote_raw(req).await?;
+        serde_json::from_slice(&body).map_err(|e| ApiError::ParseError(e.to_string()))
+    }
+
+    /// Same as `send_remote` for endpoints whose body we don't need
+    /// Everything remote goes through the scheduler for rate limiting
+    pub(super) async fn send_remote_raw(&self, mut req: reqwest::RequestBuilder) -> Result<Body, ApiError> {
         let headers: HashMap<String, String> = self.remote_headers.read().clone();
-        let mut req = self.client.post(url);
         for (k, v) in headers.iter() {
             req = req.header(k, v);
         }
-        req.json(&serde_json::json!({})).send().await.ok()?.json().await.ok()
+
+        let request = req.build().map_err(|e| ApiError::RequestFailed(e.to_string()))?;
+        self.scheduler.execute(&self.client, request).await
     }
 
     pub async fn get_pregame_match_id(&self) -> Option<String> {
@@ -208,8 +301,13 @@ impl ValorantAPI {
     }
 
     pub async fn get_pregame_match(&self, match_id: &str) -> Option<PregameMatch> {
+        self.try_get_pregame_match(match_id).await.ok()
+    }
+
+    /// Like `get_pregame_match` but keeps the error - a 404 means the match is gone
+    pub async fn try_get_pregame_match(&self, match_id: &str) -> Result<PregameMatch, ApiError> {
         let url = self.glz_url(&format!("/pregame/v1/matches/{}", match_id));
-        self.get_remote(&url).await
+        self.try_get_remote(&url).await
     }
 
     pub async fn get_coregame_match_id(&self) -> Option<String> {
@@ -226,68 +324,72 @@ impl ValorantAPI {
 
     pub async fn get_player_names(&self, puuids: &[String]) -> HashMap<String, String> {
         let url = self.pd_url("/name-service/v2/players");
-        let headers: HashMap<String, String> = self.remote_headers.read().clone();
-        let mut req = self.client.put(&url);
-        for (k, v) in headers.iter() {
-            req = req.header(k, v);
-        }
+        let req = self.client.put(&url).json(&puuids);
 
         let mut names = HashMap::new();
-        if let Ok(resp) = req.json(&puuids).send().await {
-            if let Ok(data) = resp.json::<Vec<PlayerNameInfo>>().await {
-                for p in data {
-                    let name = if p.tag_line.is_empty() {
-                        p.game_name
-                    } else {
-                        format!("{}#{}", p.game_name, p.tag_line)
-                    };
-                    names.insert(p.subject, name);
-                }
+        if let Ok(data) = self.send_remote::<Vec<PlayerNameInfo>>(req).await {
+            for p in data {
+                let name = if p.tag_line.is_empty() {
+                    p.game_name
+                } else {
+                    format!("{}#{}", p.game_name, p.tag_line)
+                };
+                names.insert(p.subject, name);
             }
         }
         names
     }
 
-    pub async fn select_agent(&self, match_id: &str, agent_id: &str) {
+    /// Get entitlement item ids of a given store item type
+    pub async fn get_entitlements(&self, item_type_id: &str) -> Option<Vec<String>> {
+        let puuid = self.puuid.read().clone();
+        let url = self.pd_url(&format!("/store/v1/entitlements/{}/{}", puuid, item_type_id));
+        let data: EntitlementsByTypeResponse = self.get_remote(&url).await?;
+        Some(data.entitlements.into_iter().map(|e| e.item_id.to_lowercase()).collect())
+    }
+
+    /// Get agent ids owned by the account, including the default agents
+    pub async fn get_owned_agents(&self) -> Option<HashSet<String>> {
+        let mut owned: HashSet<String> = self
+            .get_entitlements(ITEM_TYPE_AGENTS)
+            .await?
+            .into_iter()
+            .collect();
+
+        for name in DEFAULT_AGENTS {
+            if let Some(id) = AGENTS.get(name) {
+                owned.insert(id.to_string());
+            }
+        }
+        Some(owned)
+    }
+
+    /// Hover an agent - returns the updated pregame match
+    pub async fn select_agent(&self, match_id: &str, agent_id: &str) -> Result<PregameMatch, ApiError> {
         let url = self.glz_url(&format!("/pregame/v1/matches/{}/select/{}", match_id, agent_id));
-        let _ = self.post_remote(&url).await;
+        self.post_remote(&url).await
     }
 
-    pub async fn lock_agent(&self, match_id: &str, agent_id: &str) {
+    /// Lock an agent - returns the updated pregame match
+    pub async fn lock_agent(&self, match_id: &str, agent_id: &str) -> Result<PregameMatch, ApiError> {
         let url = self.glz_url(&format!("/pregame/v1/matches/{}/lock/{}", match_id, agent_id));
-        let _ = self.post_remote(&url).await;
+        self.post_remote(&url).await
+    }
+
+    /// Quit (dodge) the pregame match
+    pub async fn quit_pregame(&self, match_id: &str) -> Result<(), ApiError> {
+        let url = self.glz_url(&format!("/pregame/v1/matches/{}/quit", match_id));
+        let req = self.client.post(&url).json(&serde_json::json!({}));
+        self.send_remote_raw(req).await.map(|_| ())
     }
 
     /// Get presences from local chat API - returns puuid -> party_id map
     pub async fn get_presences(&self) -> HashMap<String, String> {
-        let port = self.local_port.read().clone();
-        let auth = self.local_auth.read().clone();
-        let url = format!("https://127.0.0.1:{}/chat/v4/presences", port);
-
         let mut party_map = HashMap::new();
 
-        if let Ok(resp) = self.client
-            .get(&url)
-            .header("Authorization", &auth)
-            .send()
-            .await
-        {
-            if let Ok(data) = resp.json::<PresencesResponse>().await {
-                for p in data.presences {
-                    if let Some(private_b64) = p.private {
-                        if let Ok(decoded) = STANDARD.decode(&private_b64) {
-                            if let Ok(json_str) = String::from_utf8(decoded) {
-                                if let Ok(private_data) = serde_json::from_str::<PresencePrivate>(&json_str) {
-                                    if let Some(party_id) = private_data.party_id {
-                                        if !party_id.is_empty() {
-                                            party_map.insert(p.puuid, party_id);
-                                        }
-                                    }
-                                }
-                            }
-                        }
-                    }
-                }
+        for (puuid, details) in self.get_presence_details().await {
+            if let Some(party_id) = details.party_id {
+                party_map.insert(puuid, party_id);
             }
         }
         party_map
@@ -296,22 +398,19 @@ impl ValorantAPI {
     /// Get my party info - returns (party_id, member_puuids)
     pub async fn get_my_party(&self) -> (Option<String>, Vec<String>) {
         let puuid = self.puuid.read().clone();
-        let url = self.glz_url(&format!("/parties/v1/players/{}", puuid));
-
-        if let Some(data) = self.get_remote::<PartyPlayerResponse>(&url).await {
-            if let Some(party_id) = data.current_party_id {
-                let party_url = self.glz_url(&format!("/parties/v1/parties/{}", party_id));
-                if let Some(party_data) = self.get_remote::<PartyResponse>(&party_url).await {
-                    let members: Vec<String> = party_data.members
-                        .iter()
-                        .filter_map(|m| m.subject.clone())
-                        .collect();
-                    return (Some(party_id), members);
-                }
-                return (Some(party_id), vec![puuid]);
-            }
+
+        let Some(party_id) = self.get_party_id().await else {
+            return (None, vec![]);
+        };
+
+        if let Ok(party_data) = self.get_party(&party_id).await {
+            let members: Vec<String> = party_data.members
+                .iter()
+                .filter_map(|m| m.subject.clone())
+                .collect();
+            return (Some(party_id), members);
         }
-        (None, vec![])
+        (Some(party_id), vec![puuid])
     }
 
     /// Detect parties for a list of players (legacy - kept for compatibility)
@@ -406,39 +505,104 @@ impl ValorantAPI {
         party_map
     }
 
-    /// Get player MMR/rank
-    #[allow(dead_code)]
-    pub async fn get_player_mmr(&self, puuid: &str) -> (u32, u32) {
+    /// Id of the active act, from the content service
+    pub async fn get_current_season(&self) -> Option<String> {
+        const KEY: &str = "current";
+        if let Some(season) = self.season.get(KEY) {
+            return Some(season);
+        }
+
+        let url = self.shared_url("/content-service/v3/content");
+        let data: ContentServiceResponse = self.get_remote(&url).await?;
+        let season = data
+            .seasons
+            .into_iter()
+            .find(|s| s.is_active && s.season_type.as_deref() == Some("act"))?
+            .id;
+        self.season.insert(KEY, season.clone());
+        Some(season)
+    }
+
+    /// Get player rank in the current act - (tier, RR), (0, 0) if unranked this act
+    /// None if a request failed
+    pub async fn get_player_mmr(&self, puuid: &str) -> Option<(u32, u32)> {
         let url = self.pd_url(&format!("/mmr/v1/players/{}", puuid));
-        if let Some(data) = self.get_remote::<MmrResponse>(&url).await {
-            if let Some(queue_skills) = data.queue_skills {
-                if let Some(competitive) = queue_skills.competitive {
-                    return (competitive.competitive_tier.unwrap_or(0), competitive.ranked_rating.unwrap_or(0));
-                }
-            }
-        }
-        (0, 0)
+        let (data, season) =
+            tokio::join!(self.get_remote::<MmrResponse>(&url), self.get_current_season());
+        let (data, season) = (data?, season?);
+
+        let seasonal = data
+            .queue_skills
+            .and_then(|q| q.competitive)
+            .and_then(|c| c.seasonal_info_by_season_id)
+            .and_then(|mut acts| acts.remove(&season))
+            .and_then(|info| {
+                let tier = info.competitive_tier.filter(|t| *t > 0)?;
+                Some((tier, info.ranked_rating.unwrap_or(0)))
+            });
+        if seasonal.is_some() {
+            return seasonal;
+        }
+
+        // The latest update can be from an earlier act, only trust it for this one
+        let latest = data
+            .latest_competitive_update
+            .filter(|u| u.season_id.as_deref() == Some(season.as_str()))
+            .and_then(|u| {
+                let tier = u.tier_after_update.filter(|t| *t > 0)?;
+                Some((tier, u.ranked_rating_after_update.unwrap_or(0)))
+            });
+        Some(latest.unwrap_or((0, 0)))
     }
 
     /// Get match history for a player (last N matches)
     pub async fn get_match_history(&self, puuid: &str, count: u32) -> Vec<String> {
+        self.try_get_match_history(puuid, count).await.unwrap_or_default()
+    }
+
+    /// Like `get_match_history` but None if the request failed
+    pub async fn try_get_match_history(&self, puuid: &str, count: u32) -> Option<Vec<String>> {
         let url = self.pd_url(&format!(
             "/match-history/v1/history/{}?startIndex=0&endIndex={}",
             puuid, count
         ));
 
-        if let Some(data) = self.get_remote::<MatchHistoryResponse>(&url).await {
-            if let Some(history) = data.history {
-                return history.into_iter().map(|h| h.match_id).collect();
-            }
-        }
-        vec![]
+        let data: MatchHistoryResponse = self.get_remote(&url).await?;
+        Some(data.history.unwrap_or_default().into_iter().map(|h| h.match_id).collect())
+    }
+
+    /// Kills, deaths, assists and wins over a player's last `count` matches
+    /// None if the history request failed
+    pub async fn get_player_stats(&self, puuid: &str, count: u32) -> Option<PlayerStats> {
+        let match_ids = self.try_get_match_history(puuid, count).await?;
+        let matches = self.get_match_details_many(&match_ids).await;
+        Some(stats::summarize(puuid, &matches))
     }
 
     /// Get match details (contains partyId for all players)
     pub async fn get_match_details(&self, match_id: &str) -> Option<MatchDetailsResponse> {
+        if let Some(details) = self.match_details.get(match_id) {
+            return Some(details);
+        }
+
         let url = self.pd_url(&format!("/match-details/v1/matches/{}", match_id));
-        self.get_remote(&url).await
+        let details: MatchDetailsResponse = self.get_remote(&url).await?;
+        self.match_details.insert(match_id, details.clone());
+        Some(details)
+    }
+
+    /// Fetch several matches at once, keeping the order of `match_ids`
+    /// New matches are written to disk once for the whole batch
+    pub async fn get_match_details_many(&self, match_ids: &[String]) -> Vec<MatchDetailsResponse> {
+        // Built up front - a mapping closure inside the stream trips the Send check in spawned tasks
+        let pending: Vec<_> = match_ids.iter().map(|id| self.get_match_details(id)).collect();
+        let details: Vec<MatchDetailsResponse> = stream::iter(pending)
+            .buffered(lookup::MAX_CONCURRENT)
+            .filter_map(|details| async move { details })
+            .collect()
+            .await;
+        self.match_details.flush().await;
+        details
     }
 
     /// Detect parties using match history - checks last match for party groupings
@@ -462,14 +626,12 @@ impl ValorantAPI {
         // Collect party info from both matches
         let mut all_match_parties: HashMap<String, String> = HashMap::new();
 
-        for match_id in &match_ids {
-            if let Some(details) = self.get_match_details(match_id).await {
-                if let Some(players) = details.players {
-                    for p in players {
-                        // Only add if not already found (prefer more recent match)
-                        if !all_match_parties.contains_key(&p.subject) {
-                            all_match_parties.insert(p.subject.clone(), p.party_id.clone());
-                        }
+        for details in self.get_match_details_many(&match_ids).await {
+            if let Some(players) = details.players {
+                for p in players {
+                    // Only add if not already found (prefer more recent match)
+                    if !all_match_parties.contains_key(&p.subject) {
+                        all_match_parties.insert(p.subject.clone(), p.party_id.clone());
                     }
                 }
             }
@@ -604,13 +766,11 @@ impl ValorantAPI {
                 // Collect party info from matches
                 let mut match_parties: HashMap<String, String> = HashMap::new();
 
-                for match_id in &match_ids {
-                    if let Some(details) = self.get_match_details(match_id).await {
-                        if let Some(players) = details.players {
-                            for p in players {
-                                if !match_parties.contains_key(&p.subject) {
-                                    match_parties.insert(p.subject.clone(), p.party_id.clone());
-                                }
+                for details in self.get_match_details_many(&match_ids).await {
+                    if let Some(players) = details.players {
+                        for p in players {
+                            if !match_parties.contains_key(&p.subject) {
+                                match_parties.insert(p.subject.clone(), p.party_id.clone());
                             }
                         }
                     }
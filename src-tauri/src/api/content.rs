@@ -0,0 +1,177 @@
+use crate::api::client::ValorantAPI;
+use crate::api::types::*;
+use std::collections::{HashMap, HashSet};
+
+const CONTENT_BASE: &str = "https://valorant-api.com/v1";
+
+/// Static game content from valorant-api.com, keyed by lowercase uuid
+#[derive(Debug, Clone, Default)]
+pub struct ContentCatalog {
+    pub weapons: HashMap<String, String>,
+    // Skin, skin level and chroma uuids all point at their skin
+    pub skins: HashMap<String, CatalogSkin>,
+    // Skin uuid -> weapon, levels and chromas in game order
+    pub skin_variants: HashMap<String, SkinVariants>,
+    pub default_skins: HashSet<String>,
+    pub tiers: HashMap<String, String>,
+    // Buddy and buddy level uuids
+    pub buddies: HashMap<String, String>,
+    pub sprays: HashMap<String, String>,
+    pub player_cards: HashMap<String, String>,
+    pub player_titles: HashMap<String, String>,
+    pub bundles: HashMap<String, String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct CatalogSkin {
+    pub skin_id: String,
+    // Display name of this entry (skin, level or chroma)
+    pub name: String,
+    pub tier_id: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SkinVariants {
+    pub weapon_id: String,
+    pub levels: Vec<String>,
+    pub chromas: Vec<String>,
+}
+
+impl ContentCatalog {
+    pub fn weapon_name(&self, weapon_id: &str) -> Option<String> {
+        self.weapons.get(&weapon_id.to_lowercase()).cloned()
+    }
+
+    pub fn skin(&self, id: &str) -> Option<&CatalogSkin> {
+        self.skins.get(&id.to_lowercase())
+    }
+
+    /// Name of the parent skin for any skin, level or chroma uuid
+    pub fn skin_name(&self, id: &str) -> Option<String> {
+        let skin = self.skin(id)?;
+        self.skin(&skin.skin_id).map(|s| s.name.clone())
+    }
+
+    pub fn tier_name(&self, id: &str) -> Option<String> {
+        let tier_id = self.skin(id)?.tier_id.as_ref()?;
+        self.tiers.get(tier_id).cloned()
+    }
+
+    pub fn buddy_name(&self, id: &str) -> Option<String> {
+        self.buddies.get(&id.to_lowercase()).cloned()
+    }
+
+    pub fn spray_name(&self, id: &str) -> Option<String> {
+        self.sprays.get(&id.to_lowercase()).cloned()
+    }
+
+    pub fn player_card_name(&self, id: &str) -> Option<String> {
+        self.player_cards.get(&id.to_lowercase()).cloned()
+    }
+
+    pub fn player_title_name(&self, id: &str) -> Option<String> {
+        self.player_titles.get(&id.to_lowercase()).cloned()
+    }
+
+    pub fn bundle_name(&self, id: &str) -> Option<String> {
+        self.bundles.get(&id.to_lowercase()).cloned()
+    }
+}
+
+fn named(items: Vec<NamedContent>) -> HashMap<String, String> {
+    items
+        .into_iter()
+        .filter_map(|i| Some((i.uuid.to_lowercase(), i.display_name?)))
+        .collect()
+}
+
+impl ValorantAPI {
+    async fn get_content<T: serde::de::DeserializeOwned>(&self, path: &str) -> Option<ContentResponse<T>> {
+        self.get_public(&format!("{}/{}", CONTENT_BASE, path)).await
+    }
+
+    /// Fetch the content catalog - weapons and skins are required, the rest is best effort
+    pub async fn get_content_catalog(&self) -> Option<ContentCatalog> {
+        let (weapons, tiers, buddies, sprays, cards, titles, bundles) = tokio::join!(
+            self.get_content::<WeaponContent>("weapons"),
+            self.get_content::<ContentTierContent>("contenttiers"),
+            self.get_content::<BuddyContent>("buddies"),
+            self.get_content::<NamedContent>("sprays"),
+            self.get_content::<NamedContent>("playercards"),
+            self.get_content::<PlayerTitleContent>("playertitles"),
+            self.get_content::<NamedContent>("bundles"),
+        );
+
+        let mut catalog = ContentCatalog::default();
+
+        for weapon in weapons?.data {
+            let weapon_id = weapon.uuid.to_lowercase();
+            if let Some(name) = weapon.display_name {
+                catalog.weapons.insert(weapon_id.clone(), name);
+            }
+            if let Some(default_skin) = weapon.default_skin_uuid {
+                catalog.default_skins.insert(default_skin.to_lowercase());
+            }
+
+            for skin in weapon.skins {
+                let skin_id = skin.uuid.to_lowercase();
+                let tier_id = skin.content_tier_uuid.map(|t| t.to_lowercase());
+                let entry = |name: Option<String>| CatalogSkin {
+                    skin_id: skin_id.clone(),
+                    name: name.unwrap_or_default(),
+                    tier_id: tier_id.clone(),
+                };
+
+                let mut variants = SkinVariants {
+                    weapon_id: weapon_id.clone(),
+                    levels: vec![],
+                    chromas: vec![],
+                };
+                for level in skin.levels {
+                    let level_id = level.uuid.to_lowercase();
+                    catalog.skins.insert(level_id.clone(), entry(level.display_name));
+                    variants.levels.push(level_id);
+                }
+                for chroma in skin.chromas {
+                    let chroma_id = chroma.uuid.to_lowercase();
+                    catalog.skins.insert(chroma_id.clone(), entry(chroma.display_name));
+                    variants.chromas.push(chroma_id);
+                }
+                catalog.skins.insert(skin_id.clone(), entry(skin.display_name));
+                catalog.skin_variants.insert(skin_id.clone(), variants);
+            }
+        }
+
+        if let Some(tiers) = tiers {
+            for tier in tiers.data {
+                if let Some(name) = tier.dev_name {
+                    catalog.tiers.insert(tier.uuid.to_lowercase(), name);
+                }
+            }
+        }
+
+        if let Some(buddies) = buddies {
+            for buddy in buddies.data {
+                let Some(name) = buddy.display_name else { continue };
+                for level in buddy.levels {
+                    catalog.buddies.insert(level.uuid.to_lowercase(), name.clone());
+                }
+                catalog.buddies.insert(buddy.uuid.to_lowercase(), name);
+            }
+        }
+
+        catalog.sprays = sprays.map(|r| named(r.data)).unwrap_or_default();
+        catalog.player_cards = cards.map(|r| named(r.data)).unwrap_or_default();
+        catalog.bundles = bundles.map(|r| named(r.data)).unwrap_or_default();
+        catalog.player_titles = titles
+            .map(|r| {
+                r.data
+                    .into_iter()
+                    .filter_map(|t| Some((t.uuid.to_lowercase(), t.title_text?)))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Some(catalog)
+    }
+}
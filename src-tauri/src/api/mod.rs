@@ -1,4 +1,11 @@
 pub mod client;
+pub mod content;
+pub mod party;
+pub mod personalization;
+pub mod presence;
+pub mod requestlog;
+pub mod scheduler;
+pub mod store;
 pub mod types;
 
 pub use client::ValorantAPI;
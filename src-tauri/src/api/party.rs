@@ -0,0 +1,54 @@
+use crate::api::client::{ApiError, ValorantAPI};
+use crate::api::types::*;
+
+impl ValorantAPI {
+    pub async fn get_party_id(&self) -> Option<String> {
+        let puuid = self.puuid.read().clone();
+        let url = self.glz_url(&format!("/parties/v1/players/{}", puuid));
+        let data: PartyPlayerResponse = self.get_remote(&url).await?;
+        data.current_party_id
+    }
+
+    pub async fn get_party(&self, party_id: &str) -> Result<PartyResponse, ApiError> {
+        let url = self.glz_url(&format!("/parties/v1/parties/{}", party_id));
+        self.try_get_remote(&url).await
+    }
+
+    pub async fn set_party_queue(&self, party_id: &str, queue_id: &str) -> Result<PartyResponse, ApiError> {
+        let url = self.glz_url(&format!("/parties/v1/parties/{}/queue", party_id));
+        let body = serde_json::json!({ "queueID": queue_id });
+        self.send_remote(self.client.post(&url).json(&body)).await
+    }
+
+    pub async fn set_party_accessibility(&self, party_id: &str, open: bool) -> Result<PartyResponse, ApiError> {
+        let url = self.glz_url(&format!("/parties/v1/parties/{}/accessibility", party_id));
+        let body = serde_json::json!({ "accessibility": if open { "OPEN" } else { "CLOSED" } });
+        self.send_remote(self.client.post(&url).json(&body)).await
+    }
+
+    pub async fn invite_to_party(&self, party_id: &str, name: &str, tag: &str) -> Result<PartyResponse, ApiError> {
+        let base = self.glz_url(&format!("/parties/v1/parties/{}/invites", party_id));
+        let mut url = reqwest::Url::parse(&base).map_err(|e| ApiError::RequestFailed(e.to_string()))?;
+        // Names can contain spaces, '#', '?' or '/' - pushed segments get percent-encoded
+        url.path_segments_mut()
+            .map_err(|_| ApiError::RequestFailed("Invalid party URL".into()))?
+            .extend(["name", name, "tag", tag]);
+        self.post_remote(url.as_str()).await
+    }
+
+    /// Remove a player from their party - my own puuid leaves the party
+    pub async fn remove_from_party(&self, puuid: &str) -> Result<(), ApiError> {
+        let url = self.glz_url(&format!("/parties/v1/players/{}", puuid));
+        self.send_remote_raw(self.client.delete(&url)).await.map(|_| ())
+    }
+
+    pub async fn join_matchmaking(&self, party_id: &str) -> Result<PartyResponse, ApiError> {
+        let url = self.glz_url(&format!("/parties/v1/parties/{}/matchmaking/join", party_id));
+        self.post_remote(&url).await
+    }
+
+    pub async fn leave_matchmaking(&self, party_id: &str) -> Result<PartyResponse, ApiError> {
+        let url = self.glz_url(&format!("/parties/v1/parties/{}/matchmaking/leave", party_id));
+        self.post_remote(&url).await
+    }
+}
@@ -0,0 +1,18 @@
+use crate::api::client::{ApiError, ValorantAPI};
+use crate::api::types::*;
+
+impl ValorantAPI {
+    /// My equipped guns, sprays and identity
+    pub async fn get_my_loadout(&self) -> Result<PersonalLoadout, ApiError> {
+        let puuid = self.puuid.read().clone();
+        let url = self.pd_url(&format!("/personalization/v2/players/{}/playerloadout", puuid));
+        self.try_get_remote(&url).await
+    }
+
+    /// Replace my loadout - returns the loadout as saved by the server
+    pub async fn put_my_loadout(&self, loadout: &PersonalLoadout) -> Result<PersonalLoadout, ApiError> {
+        let puuid = self.puuid.read().clone();
+        let url = self.pd_url(&format!("/personalization/v2/players/{}/playerloadout", puuid));
+        self.send_remote(self.client.put(&url).json(loadout)).await
+    }
+}
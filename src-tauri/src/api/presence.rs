@@ -0,0 +1,86 @@
+use crate::api::client::ValorantAPI;
+use crate::api::types::*;
+use crate::constants::{MAP_NAMES, QUEUE_NAMES};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use std::collections::HashMap;
+
+impl ValorantAPI {
+    /// Raw presences of everyone the chat service knows (me and friends)
+    pub async fn get_presence_list(&self) -> Vec<Presence> {
+        self.get_local::<PresencesResponse>("/chat/v4/presences")
+            .await
+            .map(|r| r.presences)
+            .unwrap_or_default()
+    }
+
+    /// Decoded VALORANT presences - puuid -> details
+    pub async fn get_presence_details(&self) -> HashMap<String, PresenceDetails> {
+        self.get_presence_list()
+            .await
+            .into_iter()
+            .filter_map(|p| {
+                let details = presence_details(&p)?;
+                Some((p.puuid, details))
+            })
+            .collect()
+    }
+
+    pub async fn get_friends(&self) -> Option<Vec<Friend>> {
+        self.get_local::<FriendsResponse>("/chat/v4/friends")
+            .await
+            .map(|r| r.friends)
+    }
+}
+
+/// Decode the private blob of a VALORANT presence
+pub fn presence_details(presence: &Presence) -> Option<PresenceDetails> {
+    if presence.product.as_deref().is_some_and(|p| p != "valorant") {
+        return None;
+    }
+    let private = decode_private(presence.private.as_deref()?)?;
+    Some(normalize(private))
+}
+
+fn decode_private(private_b64: &str) -> Option<PresencePrivate> {
+    let decoded = STANDARD.decode(private_b64).ok()?;
+    let json_str = String::from_utf8(decoded).ok()?;
+    serde_json::from_str(&json_str).ok()
+}
+
+/// Merge the flat and sectioned layouts - sections win when present
+fn normalize(p: PresencePrivate) -> PresenceDetails {
+    let matched = p.match_presence_data;
+    let party = p.party_presence_data;
+    let player = p.player_presence_data;
+
+    let non_empty = |s: Option<String>| s.filter(|s| !s.is_empty());
+
+    let session_loop_state = matched
+        .as_ref()
+        .and_then(|m| m.session_loop_state.clone())
+        .or(p.session_loop_state)
+        .unwrap_or_else(|| "MENUS".into());
+    let queue_id = non_empty(matched.as_ref().and_then(|m| m.queue_id.clone()).or(p.queue_id));
+    let map_id = non_empty(matched.as_ref().and_then(|m| m.match_map.clone()).or(p.match_map));
+    let party_state = party.as_ref().and_then(|m| m.party_state.clone()).or(p.party_state);
+
+    PresenceDetails {
+        is_queuing: party_state.as_deref() == Some("MATCHMAKING"),
+        queue_name: queue_id
+            .as_deref()
+            .map(|q| QUEUE_NAMES.get(q).map(|s| s.to_string()).unwrap_or_else(|| q.to_string())),
+        map_name: map_id
+            .as_deref()
+            .and_then(|m| MAP_NAMES.get(m))
+            .map(|s| s.to_string()),
+        party_id: non_empty(party.as_ref().and_then(|m| m.party_id.clone()).or(p.party_id)),
+        party_size: party.as_ref().and_then(|m| m.party_size).or(p.party_size).unwrap_or(1),
+        max_party_size: party.as_ref().and_then(|m| m.max_party_size).or(p.max_party_size).unwrap_or(5),
+        rank_tier: player.and_then(|m| m.competitive_tier).or(p.competitive_tier).unwrap_or(0),
+        score_ally: p.party_owner_match_score_ally_team,
+        score_enemy: p.party_owner_match_score_enemy_team,
+        is_idle: p.is_idle.unwrap_or(false),
+        session_loop_state,
+        queue_id,
+    }
+}
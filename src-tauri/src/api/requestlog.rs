@@ -0,0 +1,57 @@
+use crate::logging;
+use parking_lot::Mutex;
+use reqwest::{Method, Url};
+use serde::Serialize;
+use std::collections::VecDeque;
+use std::time::Instant;
+
+// Roughly the last few minutes of polling
+const CAPACITY: usize = 200;
+
+/// One HTTP exchange, without headers or bodies, for diagnostics bundles
+#[derive(Debug, Clone, Serialize)]
+pub struct RequestSummary {
+    pub at: String,
+    pub method: String,
+    // Host and path only - queries can carry ids, the path is redacted
+    pub url: String,
+    pub status: Option<u16>,
+    pub duration_ms: u64,
+    pub error: Option<String>,
+}
+
+/// Ring buffer of the most recent requests
+#[derive(Default)]
+pub struct RequestLog {
+    entries: Mutex<VecDeque<RequestSummary>>,
+}
+
+impl RequestLog {
+    pub fn record(&self, method: &Method, url: &Url, status: Option<u16>, started: Instant, error: Option<String>) {
+        let summary = RequestSummary {
+            at: chrono::Utc::now().to_rfc3339(),
+            method: method.to_string(),
+            url: logging::redact(&format!("{}{}", url.host_str().unwrap_or_default(), url.path())),
+            status,
+            duration_ms: started.elapsed().as_millis() as u64,
+            error: error.map(|e| logging::redact(&e)),
+        };
+        tracing::debug!(
+            "{} {} -> {} in {}ms",
+            summary.method,
+            summary.url,
+            summary.status.map_or("failed".to_string(), |s| s.to_string()),
+            summary.duration_ms
+        );
+
+        let mut entries = self.entries.lock();
+        if entries.len() == CAPACITY {
+            entries.pop_front();
+        }
+        entries.push_back(summary);
+    }
+
+    pub fn recent(&self) -> Vec<RequestSummary> {
+        self.entries.lock().iter().cloned().collect()
+    }
+}
@@ -0,0 +1,234 @@
+use crate::api::client::ApiError;
+use crate::api::requestlog::RequestLog;
+use parking_lot::Mutex;
+use reqwest::{Client, Method, Request, StatusCode};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::OnceCell;
+
+// Per-host token bucket - pd.* starts returning 429 well before this under sustained load
+const BURST: f64 = 10.0;
+const REFILL_PER_SEC: f64 = 4.0;
+const MAX_RETRIES: u32 = 2;
+const DEFAULT_RETRY_AFTER: Duration = Duration::from_secs(2);
+const MAX_RETRY_AFTER: Duration = Duration::from_secs(30);
+
+pub type Body = Arc<Vec<u8>>;
+type InFlight = Arc<OnceCell<Result<Body, ApiError>>>;
+
+/// Lower value goes first when a host is out of tokens
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Priority {
+    // Pregame/coregame/party state and agent select actions
+    Live = 0,
+    Names = 1,
+    Ranks = 2,
+    History = 3,
+}
+
+impl Priority {
+    /// Pick the priority from the endpoint path so callers don't have to
+    pub fn for_url(url: &str) -> Self {
+        if url.contains("/name-service/") {
+            Priority::Names
+        } else if url.contains("/mmr/") {
+            Priority::Ranks
+        } else if url.contains("/match-history/") || url.contains("/match-details/") {
+            Priority::History
+        } else {
+            Priority::Live
+        }
+    }
+}
+
+const PRIORITIES: usize = Priority::History as usize + 1;
+
+struct Bucket {
+    tokens: f64,
+    last_refill: Instant,
+    // Set from Retry-After after a 429
+    blocked_until: Option<Instant>,
+    waiting: [u32; PRIORITIES],
+}
+
+impl Bucket {
+    fn new() -> Self {
+        Self {
+            tokens: BURST,
+            last_refill: Instant::now(),
+            blocked_until: None,
+            waiting: [0; PRIORITIES],
+        }
+    }
+
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now.duration_since(self.last_refill).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * REFILL_PER_SEC).min(BURST);
+        self.last_refill = now;
+    }
+
+    fn higher_priority_waiting(&self, priority: Priority) -> bool {
+        self.waiting[..priority as usize].iter().any(|&n| n > 0)
+    }
+}
+
+/// Throttles remote requests per host and shares identical in-flight GETs
+#[derive(Default)]
+pub struct RequestScheduler {
+    buckets: Mutex<HashMap<String, Bucket>>,
+    // URL -> response shared by every caller of an identical GET
+    inflight: Mutex<HashMap<String, InFlight>>,
+    // Every request sent, local ones included, for diagnostics
+    pub log: RequestLog,
+    // Tests time the fan-out, not the rate limit
+    #[cfg(test)]
+    pub(crate) unthrottled: std::sync::atomic::AtomicBool,
+}
+
+impl RequestScheduler {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Send a request and return its body, failing on non-2xx statuses
+    pub async fn execute(&self, client: &Client, request: Request) -> Result<Body, ApiError> {
+        if request.method() != Method::GET {
+            return self.send(client, request).await;
+        }
+
+        // Identical GETs share the first caller's response
+        let key = request.url().to_string();
+        let cell = self.inflight.lock().entry(key.clone()).or_default().clone();
+        let result = cell.get_or_init(|| self.send(client, request)).await.clone();
+
+        let mut inflight = self.inflight.lock();
+        if inflight.get(&key).is_some_and(|c| Arc::ptr_eq(c, &cell)) {
+            inflight.remove(&key);
+        }
+        result
+    }
+
+    async fn send(&self, client: &Client, request: Request) -> Result<Body, ApiError> {
+        let host = request.url().host_str().unwrap_or_default().to_string();
+        let priority = Priority::for_url(request.url().path());
+
+        let mut attempt = 0;
+        let mut request = request;
+        loop {
+            // Keep a copy for retrying after a 429
+            let retry = request.try_clone();
+            self.acquire(&host, priority).await;
+
+            let started = Instant::now();
+            let (method, url) = (request.method().clone(), request.url().clone());
+            let resp = match client.execute(request).await {
+                Ok(resp) => resp,
+                Err(e) => {
+                    self.log.record(&method, &url, None, started, Some(e.to_string()));
+                    return Err(ApiError::RequestFailed(e.to_string()));
+                }
+            };
+
+            let status = resp.status();
+            self.log.record(&method, &url, Some(status.as_u16()), started, None);
+            if status == StatusCode::TOO_MANY_REQUESTS {
+                let wait = retry_after(&resp);
+                self.block(&host, wait);
+                if let (Some(next), true) = (retry, attempt < MAX_RETRIES) {
+                    tracing::warn!("{} rate limited, retrying in {:?}", host, wait);
+                    attempt += 1;
+                    request = next;
+                    continue;
+                }
+            }
+
+            if !status.is_success() {
+                let message = resp.text().await.unwrap_or_default();
+                // 404s are routine (not in pregame/coregame), so keep this out of the default level
+                tracing::debug!("{} {} failed: HTTP {}", method, url.path(), status.as_u16());
+                return Err(ApiError::Http { status: status.as_u16(), message });
+            }
+
+            let body = resp
+                .bytes()
+                .await
+                .map_err(|e| ApiError::RequestFailed(e.to_string()))?;
+            return Ok(Arc::new(body.to_vec()));
+        }
+    }
+
+    /// Wait for a token, letting higher priority requests on the same host go first
+    async fn acquire(&self, host: &str, priority: Priority) {
+        #[cfg(test)]
+        if self.unthrottled.load(std::sync::atomic::Ordering::SeqCst) {
+            return;
+        }
+        let _waiting = WaitGuard::new(self, host, priority);
+
+        loop {
+            let wait = {
+                let now = Instant::now();
+                let mut buckets = self.buckets.lock();
+                let bucket = buckets.entry(host.to_string()).or_insert_with(Bucket::new);
+                bucket.refill(now);
+
+                match bucket.blocked_until {
+                    Some(until) if until > now => until - now,
+                    _ if bucket.tokens >= 1.0 && !bucket.higher_priority_waiting(priority) => {
+                        bucket.tokens -= 1.0;
+                        return;
+                    }
+                    _ => {
+                        let missing = (1.0 - bucket.tokens).max(0.0);
+                        Duration::from_secs_f64(missing / REFILL_PER_SEC).max(Duration::from_millis(10))
+                    }
+                }
+            };
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    fn block(&self, host: &str, wait: Duration) {
+        let until = Instant::now() + wait;
+        let mut buckets = self.buckets.lock();
+        let bucket = buckets.entry(host.to_string()).or_insert_with(Bucket::new);
+        bucket.blocked_until = Some(bucket.blocked_until.map_or(until, |b| b.max(until)));
+        bucket.tokens = 0.0;
+    }
+}
+
+/// Counts a request as waiting until it gets a token or is cancelled
+struct WaitGuard<'a> {
+    scheduler: &'a RequestScheduler,
+    host: &'a str,
+    priority: Priority,
+}
+
+impl<'a> WaitGuard<'a> {
+    fn new(scheduler: &'a RequestScheduler, host: &'a str, priority: Priority) -> Self {
+        let mut buckets = scheduler.buckets.lock();
+        let bucket = buckets.entry(host.to_string()).or_insert_with(Bucket::new);
+        bucket.waiting[priority as usize] += 1;
+        Self { scheduler, host, priority }
+    }
+}
+
+impl Drop for WaitGuard<'_> {
+    fn drop(&mut self) {
+        if let Some(bucket) = self.scheduler.buckets.lock().get_mut(self.host) {
+            bucket.waiting[self.priority as usize] -= 1;
+        }
+    }
+}
+
+/// Retry-After in seconds, capped so a bad header can't stall the overlay
+fn retry_after(resp: &reqwest::Response) -> Duration {
+    resp.headers()
+        .get(reqwest::header::RETRY_AFTER)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.trim().parse::<u64>().ok())
+        .map(Duration::from_secs)
+        .unwrap_or(DEFAULT_RETRY_AFTER)
+        .min(MAX_RETRY_AFTER)
+}
@@ -0,0 +1,18 @@
+use crate::api::client::{ApiError, ValorantAPI};
+use crate::api::types::*;
+
+impl ValorantAPI {
+    /// Daily offers, featured bundles and night market
+    pub async fn get_storefront(&self) -> Result<StorefrontResponse, ApiError> {
+        let puuid = self.puuid.read().clone();
+        let url = self.pd_url(&format!("/store/v3/storefront/{}", puuid));
+        self.post_remote(&url).await
+    }
+
+    /// Currency balances keyed by currency id
+    pub async fn get_wallet(&self) -> Result<WalletResponse, ApiError> {
+        let puuid = self.puuid.read().clone();
+        let url = self.pd_url(&format!("/store/v1/wallet/{}", puuid));
+        self.try_get_remote(&url).await
+    }
+}
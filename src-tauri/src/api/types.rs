@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EntitlementsResponse {
@@ -44,7 +45,22 @@ pub struct PregameMatch {
     pub map_id: String,
     #[serde(rename = "QueueID")]
     pub queue_id: String,
+    #[serde(rename = "GamePodID")]
+    pub game_pod_id: Option<String>,
     pub ally_team: Option<PregameTeam>,
+    // Custom game spectators and coaches are not part of AllyTeam
+    #[serde(default)]
+    pub observer_subjects: Vec<String>,
+    #[serde(default)]
+    pub match_coaches: Vec<PregameCoach>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct PregameCoach {
+    pub subject: String,
+    #[serde(rename = "TeamID")]
+    pub team_id: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -70,6 +86,10 @@ pub struct PregamePlayerInfo {
 #[serde(rename_all = "PascalCase")]
 pub struct PlayerIdentity {
     pub account_level: i32,
+    #[serde(rename = "PlayerCardID")]
+    pub player_card_id: Option<String>,
+    #[serde(rename = "PlayerTitleID")]
+    pub player_title_id: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -84,9 +104,25 @@ pub struct CoregamePlayer {
 pub struct CoregameMatch {
     #[serde(rename = "MapID")]
     pub map_id: String,
+    #[serde(rename = "ModeID")]
+    pub mode_id: Option<String>,
+    // "Matchmaking" | "CustomGame" | "ShootingRange"
+    pub provisioning_flow: Option<String>,
+    #[serde(rename = "GamePodID")]
+    pub game_pod_id: Option<String>,
+    pub matchmaking_data: Option<CoregameMatchmakingData>,
     pub players: Vec<CoregamePlayerInfo>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct CoregameMatchmakingData {
+    #[serde(rename = "QueueID")]
+    pub queue_id: Option<String>,
+    #[serde(default)]
+    pub is_ranked: bool,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct CoregamePlayerInfo {
@@ -97,6 +133,10 @@ pub struct CoregamePlayerInfo {
     pub team_id: String,
     pub player_identity: Option<PlayerIdentity>,
     pub seasonal_badge_info: Option<SeasonalBadgeInfo>,
+    #[serde(default)]
+    pub is_observer: bool,
+    #[serde(default)]
+    pub is_coach: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -113,6 +153,130 @@ pub struct PlayerNameInfo {
     pub tag_line: String,
 }
 
+// Store entitlement types
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct EntitlementsByTypeResponse {
+    #[serde(rename = "ItemTypeID")]
+    pub item_type_id: String,
+    pub entitlements: Vec<Entitlement>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Entitlement {
+    #[serde(rename = "TypeID")]
+    pub type_id: String,
+    #[serde(rename = "ItemID")]
+    pub item_id: String,
+}
+
+// Store types
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct StorefrontResponse {
+    pub featured_bundle: Option<FeaturedBundle>,
+    pub skins_panel_layout: Option<SkinsPanelLayout>,
+    pub bonus_store: Option<BonusStore>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct FeaturedBundle {
+    pub bundles: Option<Vec<StoreBundleData>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct StoreBundleData {
+    #[serde(rename = "DataAssetID")]
+    pub data_asset_id: String,
+    #[serde(rename = "CurrencyID")]
+    pub currency_id: String,
+    pub items: Vec<BundleItem>,
+    pub duration_remaining_in_seconds: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct BundleItem {
+    pub item: OfferReward,
+    pub base_price: u32,
+    pub discounted_price: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct SkinsPanelLayout {
+    pub single_item_store_offers: Option<Vec<StoreOfferData>>,
+    pub single_item_offers_remaining_duration_in_seconds: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct StoreOfferData {
+    #[serde(rename = "OfferID")]
+    pub offer_id: String,
+    pub cost: std::collections::HashMap<String, u32>,
+    pub rewards: Vec<OfferReward>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct OfferReward {
+    #[serde(rename = "ItemTypeID")]
+    pub item_type_id: String,
+    #[serde(rename = "ItemID")]
+    pub item_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct BonusStore {
+    pub bonus_store_offers: Vec<BonusStoreOffer>,
+    pub bonus_store_remaining_duration_in_seconds: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct BonusStoreOffer {
+    pub offer: StoreOfferData,
+    pub discount_percent: u32,
+    pub discount_costs: std::collections::HashMap<String, u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct WalletResponse {
+    pub balances: std::collections::HashMap<String, u32>,
+}
+
+// Personalization types - unknown fields are kept so a PUT doesn't drop them
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct PersonalLoadout {
+    pub subject: String,
+    pub version: u32,
+    pub guns: Vec<LoadoutGun>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct LoadoutGun {
+    #[serde(rename = "ID")]
+    pub id: String,
+    #[serde(rename = "SkinID")]
+    pub skin_id: String,
+    #[serde(rename = "SkinLevelID")]
+    pub skin_level_id: String,
+    #[serde(rename = "ChromaID")]
+    pub chroma_id: String,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
 // Frontend types
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ConnectionStatus {
@@ -121,15 +285,102 @@ pub struct ConnectionStatus {
     pub message: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct GameState {
-    pub state: String, // "idle" | "pregame" | "ingame"
+    pub state: String, // "idle" | "queuing" | "pregame" | "ingame"
     pub match_id: Option<String>,
     pub map_name: Option<String>,
     pub mode_name: Option<String>,
     pub side: Option<String>,
     pub allies: Vec<PlayerData>,
     pub enemies: Vec<PlayerData>,
+    pub queue_elapsed_secs: Option<u64>,
+    pub score_ally: Option<u32>,
+    pub score_enemy: Option<u32>,
+    pub round: Option<u32>,
+    pub queue_id: Option<String>,
+    pub server: Option<ServerInfo>,
+    pub is_ranked: bool,
+    pub is_custom: bool,
+    pub is_range: bool,
+    pub layout: TeamLayout,
+    // Free-for-all modes list everyone here instead of allies/enemies
+    pub players: Vec<PlayerData>,
+    // Custom game spectators and coaches, listed apart from the teams
+    pub observers: Vec<PlayerData>,
+    pub coaches: Vec<PlayerData>,
+}
+
+// How the roster is grouped for the current mode
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TeamLayout {
+    #[default]
+    Teams,
+    FreeForAll,
+}
+
+// Game server parsed from a GamePodID
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ServerInfo {
+    pub pod_id: String,
+    pub location: String,    // "Frankfurt 1"
+    pub region: String,      // "eu"
+    pub data_center: String, // "aws-rclusterprod-euc1-1"
+    pub provider: String,    // "aws"
+}
+
+// How often this session landed on a server location
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ServerStats {
+    pub location: String,
+    pub region: String,
+    pub data_center: String,
+    pub matches: u32,
+    pub last_seen: String, // RFC 3339
+}
+
+// Emitted as "game-state-enrichment" when a background lookup for the roster finishes
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EnrichmentEvent {
+    pub match_id: String,
+    #[serde(flatten)]
+    pub data: Enrichment,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Enrichment {
+    Names { names: HashMap<String, String> },
+    Parties { parties: HashMap<String, String> },
+    Ranks { ranks: HashMap<String, RankInfo> },
+    Stats { stats: HashMap<String, PlayerStats> },
+    // Loadouts are large, the frontend fetches them with get_all_loadouts
+    Loadouts,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct RankInfo {
+    pub tier: i32,
+    pub rr: i32,
+}
+
+/// Totals over a player's last few matches
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct PlayerStats {
+    pub matches: u32,
+    pub wins: u32,
+    pub kills: u32,
+    pub deaths: u32,
+    pub assists: u32,
+}
+
+// Emitted as "phase-changed" whenever GameState.state changes
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PhaseChange {
+    pub from: String,
+    pub to: String,
+    pub match_id: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -143,10 +394,56 @@ pub struct PlayerData {
     pub rank_tier: i32,
     pub rank_rr: i32,
     pub level: i32,
+    pub stats: Option<PlayerStats>,
 }
 
+// Auto-lock result, emitted as the "auto-lock" event
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AutoLockEvent {
+    pub match_id: String,
+    pub agent: String,
+    pub outcome: AutoLockOutcome,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum AutoLockOutcome {
+    Locked,
+    AgentTaken { by: String },
+    NotOwned,
+    MatchDodged,
+    HttpError { status: Option<u16>, message: String },
+}
+
+// Team composition hints for agent select
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AgentRole {
+    Controller,
+    Duelist,
+    Initiator,
+    Sentinel,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompositionPick {
+    pub puuid: String,
+    pub agent: String,
+    pub role: Option<AgentRole>,
+    pub locked: bool,
+}
 
-// Presence types for party detection
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TeamComposition {
+    pub picks: Vec<CompositionPick>,
+    // Roles no locked agent covers
+    pub missing_roles: Vec<AgentRole>,
+    // Missing roles that are at least hovered by someone
+    pub hovered_roles: Vec<AgentRole>,
+}
+
+// Presence types - the private blob is base64 JSON, flat in older clients
+// and split into match/party/player sections in newer ones
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PresencesResponse {
     pub presences: Vec<Presence>,
@@ -156,12 +453,64 @@ pub struct PresencesResponse {
 pub struct Presence {
     pub puuid: String,
     pub private: Option<String>,
+    pub product: Option<String>,
+    pub state: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PresencePrivate {
     pub party_id: Option<String>,
+    pub session_loop_state: Option<String>,
+    pub queue_id: Option<String>,
+    pub match_map: Option<String>,
+    pub party_state: Option<String>,
+    pub party_size: Option<u32>,
+    pub max_party_size: Option<u32>,
+    pub competitive_tier: Option<i32>,
+    pub party_owner_match_score_ally_team: Option<u32>,
+    pub party_owner_match_score_enemy_team: Option<u32>,
+    pub is_idle: Option<bool>,
+    pub match_presence_data: Option<MatchPresenceData>,
+    pub party_presence_data: Option<PartyPresenceData>,
+    pub player_presence_data: Option<PlayerPresenceData>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MatchPresenceData {
+    pub session_loop_state: Option<String>,
+    pub queue_id: Option<String>,
+    pub match_map: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PartyPresenceData {
+    pub party_id: Option<String>,
+    pub party_state: Option<String>,
+    pub party_size: Option<u32>,
+    pub max_party_size: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlayerPresenceData {
+    pub competitive_tier: Option<i32>,
+}
+
+// Friends types
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FriendsResponse {
+    pub friends: Vec<Friend>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Friend {
+    pub puuid: String,
+    pub game_name: Option<String>,
+    pub game_tag: Option<String>,
+    pub note: Option<String>,
 }
 
 // Party types
@@ -175,37 +524,90 @@ pub struct PartyPlayerResponse {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct PartyResponse {
+    #[serde(rename = "ID")]
+    pub id: Option<String>,
     pub members: Vec<PartyMember>,
+    pub state: Option<String>,
+    pub accessibility: Option<String>,
+    pub matchmaking_data: Option<PartyMatchmakingData>,
+    pub queue_entry_time: Option<String>,
+    pub eligible_queues: Option<Vec<String>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct PartyMember {
     pub subject: Option<String>,
+    pub competitive_tier: Option<i32>,
+    pub player_identity: Option<PlayerIdentity>,
+    pub is_owner: Option<bool>,
+    pub is_ready: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct PartyMatchmakingData {
+    #[serde(rename = "QueueID")]
+    pub queue_id: Option<String>,
 }
 
 // MMR types
-#[allow(dead_code)]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct MmrResponse {
     pub queue_skills: Option<QueueSkills>,
+    pub latest_competitive_update: Option<CompetitiveUpdate>,
 }
 
-#[allow(dead_code)]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QueueSkills {
     pub competitive: Option<CompetitiveSkill>,
 }
 
-#[allow(dead_code)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct CompetitiveUpdate {
+    #[serde(rename = "SeasonID")]
+    pub season_id: Option<String>,
+    pub tier_after_update: Option<u32>,
+    pub ranked_rating_after_update: Option<u32>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct CompetitiveSkill {
+    // Act id -> rank in that act
+    #[serde(rename = "SeasonalInfoBySeasonID")]
+    pub seasonal_info_by_season_id: Option<HashMap<String, SeasonalInfo>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct SeasonalInfo {
     pub competitive_tier: Option<u32>,
     pub ranked_rating: Option<u32>,
 }
 
+// Content service types
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ContentServiceResponse {
+    #[serde(default)]
+    pub seasons: Vec<ContentSeason>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ContentSeason {
+    #[serde(rename = "ID")]
+    pub id: String,
+    // "episode" or "act"
+    #[serde(rename = "Type")]
+    pub season_type: Option<String>,
+    #[serde(default)]
+    pub is_active: bool,
+}
+
 // Match History types
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
@@ -230,6 +632,15 @@ pub struct MatchHistoryEntry {
 pub struct MatchDetailsResponse {
     pub match_info: Option<MatchInfo>,
     pub players: Option<Vec<MatchPlayer>>,
+    pub teams: Option<Vec<MatchTeam>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MatchTeam {
+    pub team_id: String,
+    #[serde(default)]
+    pub won: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -246,6 +657,15 @@ pub struct MatchPlayer {
     pub subject: String,
     pub party_id: String,
     pub team_id: Option<String>,
+    pub stats: Option<MatchPlayerStats>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct MatchPlayerStats {
+    pub kills: u32,
+    pub deaths: u32,
+    pub assists: u32,
 }
 
 // Loadout types
@@ -268,21 +688,30 @@ pub struct PlayerLoadout {
 pub struct LoadoutData {
     pub subject: String,
     pub items: std::collections::HashMap<String, LoadoutItem>,
+    pub sprays: Option<SpraySelections>,
 }
 
-// Pregame loadout types (different structure)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
-pub struct PregameLoadoutsResponse {
-    pub loadouts: Vec<PregameLoadoutData>,
-    pub loadouts_valid: Option<bool>,
+pub struct SpraySelections {
+    pub spray_selections: Vec<SpraySelection>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
-pub struct PregameLoadoutData {
-    pub subject: String,
-    pub items: std::collections::HashMap<String, LoadoutItem>,
+pub struct SpraySelection {
+    #[serde(rename = "SocketID")]
+    pub socket_id: String,
+    #[serde(rename = "SprayID")]
+    pub spray_id: String,
+}
+
+// Pregame loadouts - not wrapped per character, same data otherwise
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct PregameLoadoutsResponse {
+    pub loadouts: Vec<LoadoutData>,
+    pub loadouts_valid: Option<bool>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -317,11 +746,192 @@ pub struct SocketItemData {
 pub struct PlayerSkinData {
     pub puuid: String,
     pub skins: Vec<WeaponSkin>,
+    pub sprays: Vec<NamedItem>,
+    pub player_card: Option<NamedItem>,
+    pub player_title: Option<NamedItem>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WeaponSkin {
     pub weapon_id: String,
+    pub weapon_name: Option<String>,
     pub skin_id: String,
+    pub skin_name: Option<String>,
+    pub level_id: Option<String>,
     pub chroma_id: Option<String>,
+    pub chroma_name: Option<String>,
+    pub tier: Option<String>,
+    pub buddy_id: Option<String>,
+    pub buddy_name: Option<String>,
+}
+
+// Owned skin with the levels and chromas the account can equip
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OwnedSkin {
+    pub weapon_id: String,
+    pub weapon_name: Option<String>,
+    pub skin_id: String,
+    pub name: String,
+    pub tier: Option<String>,
+    pub levels: Vec<String>,
+    pub chromas: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NamedItem {
+    pub id: String,
+    pub name: Option<String>,
+}
+
+// Frontend store types
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Storefront {
+    pub daily_offers: Vec<StoreOffer>,
+    pub daily_remaining_secs: u64,
+    pub bundles: Vec<StoreBundle>,
+    pub night_market: Option<NightMarket>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StoreOffer {
+    pub item_id: String,
+    pub name: Option<String>,
+    pub tier: Option<String>,
+    pub price: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StoreBundle {
+    pub bundle_id: String,
+    pub name: Option<String>,
+    pub items: Vec<StoreOffer>,
+    pub base_price: u32,
+    pub discounted_price: u32,
+    pub remaining_secs: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NightMarket {
+    pub offers: Vec<NightMarketOffer>,
+    pub remaining_secs: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NightMarketOffer {
+    pub offer: StoreOffer,
+    pub discount_percent: u32,
+    pub discounted_price: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Wallet {
+    pub valorant_points: u32,
+    pub radianite: u32,
+    pub kingdom_credits: u32,
+}
+
+// Frontend party types
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PartyInfo {
+    pub party_id: String,
+    pub state: String, // "DEFAULT" | "MATCHMAKING" | "MATCHMADE_GAME_STARTING"
+    pub queue_id: Option<String>,
+    pub queue_name: Option<String>,
+    pub is_open: bool,
+    pub eligible_queues: Vec<String>,
+    pub members: Vec<PartyMemberInfo>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PartyMemberInfo {
+    pub puuid: String,
+    pub name: String,
+    pub is_me: bool,
+    pub is_owner: bool,
+    pub is_ready: bool,
+    pub rank_tier: i32,
+    pub level: i32,
+    pub player_card_id: Option<String>,
+}
+
+// Decoded presence, flat and normalized for the frontend
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PresenceDetails {
+    pub session_loop_state: String, // "MENUS" | "PREGAME" | "INGAME"
+    pub is_queuing: bool,
+    pub queue_id: Option<String>,
+    pub queue_name: Option<String>,
+    pub map_name: Option<String>,
+    pub party_id: Option<String>,
+    pub party_size: u32,
+    pub max_party_size: u32,
+    pub rank_tier: i32,
+    pub score_ally: Option<u32>,
+    pub score_enemy: Option<u32>,
+    pub is_idle: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FriendInfo {
+    pub puuid: String,
+    pub name: String,
+    pub note: Option<String>,
+    pub status: String, // "offline" | "online" | "menus" | "queuing" | "pregame" | "ingame"
+    pub availability: Option<String>, // "chat" | "away" | "dnd" ...
+    pub presence: Option<PresenceDetails>,
+}
+
+// valorant-api.com content types
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContentResponse<T> {
+    pub data: Vec<T>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NamedContent {
+    pub uuid: String,
+    pub display_name: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WeaponContent {
+    pub uuid: String,
+    pub display_name: Option<String>,
+    pub default_skin_uuid: Option<String>,
+    pub skins: Vec<SkinContent>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SkinContent {
+    pub uuid: String,
+    pub display_name: Option<String>,
+    pub content_tier_uuid: Option<String>,
+    pub chromas: Vec<NamedContent>,
+    pub levels: Vec<NamedContent>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BuddyContent {
+    pub uuid: String,
+    pub display_name: Option<String>,
+    pub levels: Vec<NamedContent>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContentTierContent {
+    pub uuid: String,
+    pub dev_name: Option<String>,
+    pub rank: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlayerTitleContent {
+    pub uuid: String,
+    pub title_text: Option<String>,
 }
@@ -0,0 +1,156 @@
+use crate::api::client::ApiError;
+use crate::api::types::{AutoLockEvent, AutoLockOutcome, PregameMatch};
+use crate::commands::get_cached_owned_agents;
+use crate::constants::AGENTS;
+use crate::state::AppState;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, Manager};
+
+const MAX_ATTEMPTS: u32 = 5;
+const RETRY_DELAY: Duration = Duration::from_millis(500);
+
+/// Start the auto-lock task for a pregame match - only once per match id
+pub fn trigger(app: &AppHandle, match_id: &str) {
+    let state = app.state::<AppState>();
+    if state.settings.get().auto_lock().is_none() {
+        return;
+    }
+
+    {
+        let mut last = state.auto_lock_match_id.write();
+        if last.as_deref() == Some(match_id) {
+            return;
+        }
+        *last = Some(match_id.to_string());
+    }
+
+    let app = app.clone();
+    let match_id = match_id.to_string();
+    tauri::async_runtime::spawn(async move {
+        if let Some(event) = run(&app, &match_id).await {
+            let _ = app.emit("auto-lock", event);
+        }
+    });
+}
+
+/// Select and lock the configured agent, retrying transient failures
+/// Returns None when auto-lock was turned off or I already locked manually
+async fn run(app: &AppHandle, match_id: &str) -> Option<AutoLockEvent> {
+    let state = app.state::<AppState>();
+    let api = state.api.clone();
+    let my_puuid = api.puuid.read().clone();
+
+    let mut last_error: Option<ApiError> = None;
+
+    for attempt in 0..MAX_ATTEMPTS {
+        if attempt > 0 {
+            tokio::time::sleep(RETRY_DELAY).await;
+        }
+
+        // Re-read every attempt so changes from the UI apply mid-pregame
+        let agent = state.settings.get().auto_lock()?.to_lowercase();
+        let agent_id = *AGENTS.get(agent.as_str())?;
+
+        let event = |outcome| AutoLockEvent {
+            match_id: match_id.to_string(),
+            agent: agent.clone(),
+            outcome,
+        };
+
+        // Unknown ownership (request failed) shouldn't block the attempt
+        if let Some(owned) = get_cached_owned_agents(&state).await {
+            if !owned.contains(agent_id) {
+                return Some(event(AutoLockOutcome::NotOwned));
+            }
+        }
+
+        // Only a 404 means the match is gone - timeouts, 5xx and 429s are retried
+        let match_data = match api.try_get_pregame_match(match_id).await {
+            Ok(match_data) => match_data,
+            Err(ApiError::Http { status: 404, .. }) => return Some(event(AutoLockOutcome::MatchDodged)),
+            Err(e) => {
+                last_error = Some(e);
+                continue;
+            }
+        };
+
+        match check_match(&match_data, &my_puuid, agent_id) {
+            Precheck::Proceed => {}
+            Precheck::AlreadyLocked => return None,
+            Precheck::Done(outcome) => return Some(event(outcome)),
+        }
+
+        let result = match api.select_agent(match_id, agent_id).await {
+            Ok(_) => api.lock_agent(match_id, agent_id).await,
+            Err(e) => Err(e),
+        };
+
+        match result {
+            Ok(updated) => match check_match(&updated, &my_puuid, agent_id) {
+                Precheck::Done(outcome) => return Some(event(outcome)),
+                _ => last_error = None,
+            },
+            Err(ApiError::Http { status: 404, .. }) => {
+                return Some(event(AutoLockOutcome::MatchDodged));
+            }
+            Err(e) => last_error = Some(e),
+        }
+    }
+
+    let agent = state.settings.get().auto_lock()?.to_lowercase();
+    let outcome = match last_error {
+        Some(ApiError::Http { status, message }) => AutoLockOutcome::HttpError {
+            status: Some(status),
+            message,
+        },
+        Some(e) => AutoLockOutcome::HttpError {
+            status: None,
+            message: e.to_string(),
+        },
+        None => AutoLockOutcome::HttpError {
+            status: None,
+            message: "Lock not confirmed".into(),
+        },
+    };
+
+    Some(AutoLockEvent {
+        match_id: match_id.to_string(),
+        agent,
+        outcome,
+    })
+}
+
+enum Precheck {
+    Proceed,
+    AlreadyLocked,
+    Done(AutoLockOutcome),
+}
+
+fn check_match(match_data: &PregameMatch, my_puuid: &str, agent_id: &str) -> Precheck {
+    let Some(team) = &match_data.ally_team else {
+        return Precheck::Proceed;
+    };
+
+    if let Some(me) = team.players.iter().find(|p| p.subject == my_puuid) {
+        if me.character_selection_state == "locked" {
+            return if me.character_id.eq_ignore_ascii_case(agent_id) {
+                Precheck::Done(AutoLockOutcome::Locked)
+            } else {
+                Precheck::AlreadyLocked
+            };
+        }
+    }
+
+    let taken_by = team.players.iter().find(|p| {
+        p.subject != my_puuid
+            && p.character_selection_state == "locked"
+            && p.character_id.eq_ignore_ascii_case(agent_id)
+    });
+    if let Some(teammate) = taken_by {
+        return Precheck::Done(AutoLockOutcome::AgentTaken {
+            by: teammate.subject.clone(),
+        });
+    }
+
+    Precheck::Proceed
+}
@@ -0,0 +1,262 @@
+use parking_lot::{Mutex, RwLock};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+// Per-endpoint lifetimes
+pub const NAMES_TTL: Ttl = Ttl::For(Duration::from_secs(6 * 60 * 60));
+// RR only changes between matches, so ranks are scoped to the match id
+pub const RANKS_TTL: Ttl = Ttl::Forever;
+// Same for recent match stats
+pub const STATS_TTL: Ttl = Ttl::Forever;
+// Parties and history lookups live for a game session and are cleared in the lobby,
+// the TTL only guards against a missed idle transition
+pub const SESSION_TTL: Ttl = Ttl::For(Duration::from_secs(3 * 60 * 60));
+// Ingame loadouts change between rounds, so refresh them on the next request
+pub const LOADOUTS_TTL: Ttl = Ttl::For(Duration::from_secs(90));
+pub const MATCH_DETAILS_TTL: Ttl = Ttl::Forever;
+// A match is ~100 KB of JSON and only the last couple per player are looked at
+pub const MATCH_DETAILS_MAX: usize = 100;
+pub const CONTENT_TTL: Ttl = Ttl::Forever;
+// Acts change every couple of months, an hour keeps a long session from missing one
+pub const SEASON_TTL: Ttl = Ttl::For(Duration::from_secs(60 * 60));
+
+#[derive(Debug, Clone, Copy)]
+pub enum Ttl {
+    For(Duration),
+    Forever,
+}
+
+struct Entry<V> {
+    value: V,
+    stored_at: SystemTime,
+}
+
+#[derive(Serialize, Deserialize)]
+struct DiskEntry<V> {
+    value: V,
+    stored_at: u64, // unix seconds
+}
+
+#[derive(Serialize, Deserialize)]
+struct DiskFile<V> {
+    scope: Option<String>,
+    entries: HashMap<String, DiskEntry<V>>,
+}
+
+/// String-keyed cache with a TTL and an optional scope (match id, client version...)
+/// Changing the scope drops every entry stored under the previous one
+pub struct Cache<V> {
+    ttl: Ttl,
+    // Oldest entries are evicted past this
+    max_entries: Option<usize>,
+    entries: RwLock<HashMap<String, Entry<V>>>,
+    scope: RwLock<Option<String>>,
+    disk: RwLock<Option<PathBuf>>,
+    // Changed since the last flush
+    dirty: AtomicBool,
+    // Bumped per flush, the writer keeps the last one on disk so older snapshots never win
+    flush_generation: AtomicU64,
+    written: Arc<Mutex<u64>>,
+}
+
+impl<V: Clone> Cache<V> {
+    pub fn new(ttl: Ttl) -> Self {
+        Self {
+            ttl,
+            max_entries: None,
+            entries: RwLock::new(HashMap::new()),
+            scope: RwLock::new(None),
+            disk: RwLock::new(None),
+            dirty: AtomicBool::new(false),
+            flush_generation: AtomicU64::new(0),
+            written: Arc::new(Mutex::new(0)),
+        }
+    }
+
+    pub fn with_max_entries(mut self, max_entries: usize) -> Self {
+        self.max_entries = Some(max_entries);
+        self
+    }
+
+    /// Drop the oldest entries until the cache fits
+    fn evict(&self, entries: &mut HashMap<String, Entry<V>>) {
+        let Some(max) = self.max_entries else { return };
+        if entries.len() <= max {
+            return;
+        }
+
+        let mut by_age: Vec<(SystemTime, String)> =
+            entries.iter().map(|(k, e)| (e.stored_at, k.clone())).collect();
+        by_age.sort();
+        for (_, key) in by_age.into_iter().take(entries.len() - max) {
+            entries.remove(&key);
+        }
+    }
+
+    fn is_fresh(&self, entry: &Entry<V>) -> bool {
+        match self.ttl {
+            Ttl::Forever => true,
+            Ttl::For(ttl) => entry.stored_at.elapsed().map(|age| age < ttl).unwrap_or(false),
+        }
+    }
+
+    pub fn get(&self, key: &str) -> Option<V> {
+        let entries = self.entries.read();
+        let entry = entries.get(key)?;
+        self.is_fresh(entry).then(|| entry.value.clone())
+    }
+
+    pub fn contains(&self, key: &str) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Fresh values for the keys that are cached - missing keys are left out
+    pub fn get_many(&self, keys: &[String]) -> HashMap<String, V> {
+        keys.iter()
+            .filter_map(|k| Some((k.clone(), self.get(k)?)))
+            .collect()
+    }
+
+    /// Every fresh entry
+    pub fn entries(&self) -> HashMap<String, V> {
+        self.entries
+            .read()
+            .iter()
+            .filter(|(_, e)| self.is_fresh(e))
+            .map(|(k, e)| (k.clone(), e.value.clone()))
+            .collect()
+    }
+
+    pub fn insert(&self, key: impl Into<String>, value: V) {
+        let entry = Entry {
+            value,
+            stored_at: SystemTime::now(),
+        };
+        let mut entries = self.entries.write();
+        entries.insert(key.into(), entry);
+        self.evict(&mut entries);
+        self.dirty.store(true, Ordering::SeqCst);
+    }
+
+    pub fn insert_many(&self, items: impl IntoIterator<Item = (String, V)>) {
+        let now = SystemTime::now();
+        let mut entries = self.entries.write();
+        for (key, value) in items {
+            entries.insert(key, Entry { value, stored_at: now });
+        }
+        self.evict(&mut entries);
+        self.dirty.store(true, Ordering::SeqCst);
+    }
+
+    pub fn clear(&self) {
+        self.entries.write().clear();
+        self.dirty.store(true, Ordering::SeqCst);
+    }
+
+    pub fn in_scope(&self, scope: &str) -> bool {
+        self.scope.read().as_deref() == Some(scope)
+    }
+
+    /// Switch to a new scope, clearing entries from the old one
+    /// Returns false if the scope didn't change
+    pub fn set_scope(&self, scope: &str) -> bool {
+        let mut current = self.scope.write();
+        if current.as_deref() == Some(scope) {
+            return false;
+        }
+        *current = Some(scope.to_string());
+        self.entries.write().clear();
+        self.dirty.store(true, Ordering::SeqCst);
+        true
+    }
+}
+
+/// Disk tier - entries survive restarts, expired ones are dropped on load
+impl<V: Clone + Serialize + DeserializeOwned> Cache<V> {
+    pub fn attach_disk(&self, path: PathBuf) {
+        if let Some(file) = std::fs::read(&path)
+            .ok()
+            .and_then(|bytes| serde_json::from_slice::<DiskFile<V>>(&bytes).ok())
+        {
+            *self.scope.write() = file.scope;
+            let mut entries = self.entries.write();
+            for (key, e) in file.entries {
+                let entry = Entry {
+                    value: e.value,
+                    stored_at: UNIX_EPOCH + Duration::from_secs(e.stored_at),
+                };
+                if self.is_fresh(&entry) {
+                    entries.entry(key).or_insert(entry);
+                }
+            }
+            self.evict(&mut entries);
+        }
+        *self.disk.write() = Some(path);
+    }
+
+    /// Write the cache to its disk tier, if one is attached and anything changed
+    /// Callers batch their inserts and flush once - the write happens off the async threads
+    pub async fn flush(&self) {
+        let Some(path) = self.disk.read().clone() else { return };
+        if !self.dirty.swap(false, Ordering::SeqCst) {
+            return;
+        }
+
+        // The generation is taken under the same locks as the snapshot, so a later
+        // generation always holds newer entries
+        let (file, generation) = {
+            let scope = self.scope.read();
+            let entries = self.entries.read();
+            let generation = self.flush_generation.fetch_add(1, Ordering::SeqCst) + 1;
+            let file = DiskFile {
+                scope: scope.clone(),
+                entries: entries
+                    .iter()
+                    .filter(|(_, e)| self.is_fresh(e))
+                    .map(|(k, e)| {
+                        let stored_at = e.stored_at.duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+                        (k.clone(), DiskEntry { value: e.value.clone(), stored_at })
+                    })
+                    .collect(),
+            };
+            (file, generation)
+        };
+
+        let Ok(bytes) = serde_json::to_vec(&file) else { return };
+        let written = self.written.clone();
+
+        let result = tokio::task::spawn_blocking(move || {
+            let mut last = written.lock();
+            // A newer snapshot is already on disk
+            if *last > generation {
+                return Ok(());
+            }
+            write_atomic(&path, &bytes).inspect_err(|e| {
+                tracing::warn!("Failed to write cache {}: {}", path.display(), e);
+            })?;
+            *last = generation;
+            Ok::<_, std::io::Error>(())
+        })
+        .await;
+
+        // Try again on the next flush
+        if !matches!(result, Ok(Ok(()))) {
+            self.dirty.store(true, Ordering::SeqCst);
+        }
+    }
+}
+
+/// Write through a temp file so a crash mid-write can't leave a truncated file
+pub(crate) fn write_atomic(path: &Path, bytes: &[u8]) -> std::io::Result<()> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    let tmp = path.with_extension("tmp");
+    std::fs::write(&tmp, bytes)?;
+    std::fs::rename(&tmp, path)
+}
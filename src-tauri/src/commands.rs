@@ -1,27 +1,62 @@
+use crate::api::content::ContentCatalog;
 use crate::api::types::*;
-use crate::constants::{AGENTS, MAP_NAMES, QUEUE_NAMES};
+use crate::autolock;
+use crate::composition;
+use crate::enrich;
+use crate::constants::{AGENTS, ITEM_TYPE_SKIN_CHROMAS, ITEM_TYPE_SKIN_LEVELS, MAP_NAMES, QUEUE_NAMES};
+use crate::friends;
+use crate::gamemode::MatchMode;
+use crate::hotkeys::{self, HotkeyAction, HotkeyError};
+use crate::hud;
+use crate::inventory;
+use crate::logging;
+use crate::lookup;
+use crate::overlay;
+use crate::party;
+use crate::phase;
+use crate::scoreboard;
+use crate::server;
+use crate::settings::{self, OverlaySettings, Settings};
+use crate::stats;
+use crate::loadout::{self, LoadoutPhase};
 use crate::state::AppState;
-use std::collections::HashMap;
-use tauri::State;
+use crate::store;
+use crate::teams::{self, RosterEntry};
+use crate::tray;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use tauri::{AppHandle, Emitter, Manager, State};
+use tauri_plugin_opener::OpenerExt;
 
 #[tauri::command]
-pub async fn initialize(state: State<'_, AppState>) -> Result<ConnectionStatus, String> {
-    state.api.initialize().await.map_err(|e| e.to_string())
+pub async fn initialize(app: AppHandle, state: State<'_, AppState>) -> Result<ConnectionStatus, String> {
+    // New session - the account may have changed
+    *state.owned_agents.write() = None;
+    let status = state.api.initialize().await.map_err(|e| e.to_string());
+    match &status {
+        Ok(status) => tracing::info!("Connected to the Riot client, region {}", status.region),
+        Err(e) => tracing::info!("Not connected: {}", e),
+    }
+    tray::refresh(&app);
+    status
 }
 
 #[tauri::command]
-pub async fn get_game_state(state: State<'_, AppState>) -> Result<GameState, String> {
+pub async fn get_game_state(app: AppHandle, state: State<'_, AppState>) -> Result<GameState, String> {
+    let game_state = build_game_state(&app, &state).await?;
+    phase::update(&app, &game_state);
+    // The HUD doesn't poll, it mirrors what the main window fetched
+    let _ = app.emit_to(hud::LABEL, "game-state", &game_state);
+    Ok(game_state)
+}
+
+async fn build_game_state(app: &AppHandle, state: &State<'_, AppState>) -> Result<GameState, String> {
     let api = &state.api;
 
     if !*api.connected.read() {
         return Ok(GameState {
             state: "disconnected".into(),
-            match_id: None,
-            map_name: None,
-            mode_name: None,
-            side: None,
-            allies: vec![],
-            enemies: vec![],
+            ..Default::default()
         });
     }
 
@@ -41,31 +76,31 @@ pub async fn get_game_state(state: State<'_, AppState>) -> Result<GameState, Str
             if let Some(team) = match_data.ally_team {
                 let side = if team.team_id == "Red" { "SALDIRAN" } else { "SAVUNAN" };
                 let puuids: Vec<String> = team.players.iter().map(|p| p.subject.clone()).collect();
-                let names = api.get_player_names(&puuids).await;
-
-                // Get parties with caching - only fetch once per match
-                let parties = get_cached_parties(&state, &match_id, &puuids, api).await;
-
-                // Check if I'm already locked
-                let my_player = team.players.iter().find(|p| p.subject == my_puuid);
-                let im_locked = my_player.map(|p| p.character_selection_state == "locked").unwrap_or(false);
-
-                // Auto-lock: keep trying until locked
-                if !im_locked {
-                    let auto_lock_agent = state.auto_lock_agent.read().clone();
-                    if let Some(agent_name) = auto_lock_agent.as_ref() {
-                        if let Some(agent_id) = AGENTS.get(agent_name.to_lowercase().as_str()) {
-                            api.select_agent(&match_id, agent_id).await;
-                            tokio::time::sleep(tokio::time::Duration::from_millis(200)).await;
-                            api.lock_agent(&match_id, agent_id).await;
-                        }
-                    }
-                }
+                let coaches: Vec<String> = match_data.match_coaches.iter()
+                    .filter(|c| c.team_id.as_deref().is_none_or(|t| t == team.team_id))
+                    .map(|c| c.subject.clone())
+                    .collect();
+                let lookup: Vec<String> = puuids.iter()
+                    .chain(&match_data.observer_subjects)
+                    .chain(&coaches)
+                    .cloned()
+                    .collect();
+                // Bare roster from whatever is cached - the rest streams in as enrichment events
+                let RosterInfo { names, parties, ranks, stats } = cached_roster_info(state, &match_id, &lookup);
+                let incomplete = names.len() < lookup.len()
+                    || ranks.len() < puuids.len()
+                    || stats.len() < puuids.len();
+                enrich::trigger(app, &match_id, LoadoutPhase::Pregame, lookup.clone(), incomplete);
+
+                // Auto-lock and loadout prefetch run in their own tasks, started once per match
+                autolock::trigger(app, &match_id);
+                loadout::trigger_prefetch(app, &match_id, LoadoutPhase::Pregame);
 
                 for p in team.players {
                     let agent_name = get_agent_name(&p.character_id);
                     let level = p.player_identity.map(|i| i.account_level).unwrap_or(0);
                     let party = parties.get(&p.subject).cloned().unwrap_or_else(|| "Solo".into());
+                    let (mmr_tier, rr) = ranks.get(&p.subject).map(|r| (r.tier, r.rr)).unwrap_or_default();
 
                     allies.push(PlayerData {
                         puuid: p.subject.clone(),
@@ -74,12 +109,27 @@ pub async fn get_game_state(state: State<'_, AppState>) -> Result<GameState, Str
                         locked: p.character_selection_state == "locked",
                         party,
                         is_me: p.subject == my_puuid,
-                        rank_tier: p.competitive_tier,
-                        rank_rr: 0,
+                        rank_tier: if p.competitive_tier > 0 { p.competitive_tier } else { mmr_tier },
+                        rank_rr: rr,
                         level,
+                        stats: stats.get(&p.subject).copied(),
                     });
                 }
 
+                let bystander = |puuid: &String| teams::bystander(
+                    puuid,
+                    names.get(puuid).cloned().unwrap_or_else(|| "Unknown".into()),
+                    parties.get(puuid).cloned().unwrap_or_else(|| "Solo".into()),
+                    *puuid == my_puuid,
+                );
+                let observers = match_data.observer_subjects.iter().map(bystander).collect();
+                let coaches = coaches.iter().map(bystander).collect();
+
+                let server = match_data.game_pod_id.as_deref().and_then(server::parse_pod_id);
+                if let Some(server) = &server {
+                    server::record_match(state, &match_id, server);
+                }
+
                 return Ok(GameState {
                     state: "pregame".into(),
                     match_id: Some(match_id),
@@ -88,6 +138,10 @@ pub async fn get_game_state(state: State<'_, AppState>) -> Result<GameState, Str
                     side: Some(side.into()),
                     allies,
                     enemies: vec![],
+                    server,
+                    observers,
+                    coaches,
+                    ..Default::default()
                 });
             }
         }
@@ -99,28 +153,27 @@ pub async fn get_game_state(state: State<'_, AppState>) -> Result<GameState, Str
             let map_name = MAP_NAMES.get(match_data.map_id.as_str())
                 .map(|s| s.to_string())
                 .unwrap_or_else(|| "Unknown".into());
+            let mode = MatchMode::from_coregame(&match_data);
 
             let my_puuid = api.puuid.read().clone();
             let puuids: Vec<String> = match_data.players.iter().map(|p| p.subject.clone()).collect();
-            let names = api.get_player_names(&puuids).await;
+            let RosterInfo { names, parties, ranks, stats } = cached_roster_info(state, &match_id, &puuids);
+            let incomplete = names.len() < puuids.len()
+                || ranks.len() < puuids.len()
+                || stats.len() < puuids.len();
+            enrich::trigger(app, &match_id, LoadoutPhase::Ingame, puuids.clone(), incomplete);
 
-            // Get parties with caching
-            let parties = get_cached_parties(&state, &match_id, &puuids, api).await;
-
-            let my_team = match_data.players.iter()
-                .find(|p| p.subject == my_puuid)
-                .map(|p| p.team_id.clone())
-                .unwrap_or_default();
-
-            let mut allies = vec![];
-            let mut enemies = vec![];
+            loadout::trigger_prefetch(app, &match_id, LoadoutPhase::Ingame);
 
+            let mut players = vec![];
             for p in match_data.players {
                 let agent_name = get_agent_name(&p.character_id);
                 let level = p.player_identity.map(|i| i.account_level).unwrap_or(0);
-                let rank = p.seasonal_badge_info.and_then(|s| s.rank).unwrap_or(0);
+                let (mmr_tier, rr) = ranks.get(&p.subject).map(|r| (r.tier, r.rr)).unwrap_or_default();
+                let rank = p.seasonal_badge_info.and_then(|s| s.rank).filter(|r| *r > 0).unwrap_or(mmr_tier);
                 let party = parties.get(&p.subject).cloned().unwrap_or_else(|| "Solo".into());
 
+                let slot = teams::slot_for(&p.team_id, p.is_observer, p.is_coach);
                 let player = PlayerData {
                     puuid: p.subject.clone(),
                     name: names.get(&p.subject).cloned().unwrap_or_else(|| "Unknown".into()),
@@ -129,25 +182,49 @@ pub async fn get_game_state(state: State<'_, AppState>) -> Result<GameState, Str
                     party,
                     is_me: p.subject == my_puuid,
                     rank_tier: rank,
-                    rank_rr: 0,
+                    rank_rr: rr,
                     level,
+                    stats: stats.get(&p.subject).copied(),
                 };
+                players.push(RosterEntry { team_id: p.team_id, slot, player });
+            }
 
-                if p.team_id == my_team {
-                    allies.push(player);
-                } else {
-                    enemies.push(player);
-                }
+            let roster = teams::group(players, mode.rules_queue());
+
+            // Score and round only live in my presence, refreshed every round
+            let presence = api.get_presence_details().await.remove(&my_puuid);
+            let score = presence.as_ref().and_then(scoreboard::live_score);
+            let side = score.and_then(|(_, _, round)| {
+                let half = scoreboard::half_length(mode.rules_queue())?;
+                Some(scoreboard::current_side(&roster.ally_team, round, half).to_string())
+            });
+
+            let server = match_data.game_pod_id.as_deref().and_then(server::parse_pod_id);
+            if let Some(server) = &server {
+                server::record_match(state, &match_id, server);
             }
 
             return Ok(GameState {
                 state: "ingame".into(),
                 match_id: Some(match_id),
                 map_name: Some(map_name),
-                mode_name: None,
-                side: None,
-                allies,
-                enemies,
+                mode_name: mode.mode_name,
+                side,
+                allies: roster.allies,
+                enemies: roster.enemies,
+                score_ally: score.map(|(ally, _, _)| ally),
+                score_enemy: score.map(|(_, enemy, _)| enemy),
+                round: score.map(|(_, _, round)| round),
+                queue_id: mode.queue_id,
+                server,
+                is_ranked: mode.is_ranked,
+                is_custom: mode.is_custom,
+                is_range: mode.is_range,
+                layout: roster.layout,
+                players: roster.players,
+                observers: roster.observers,
+                coaches: roster.coaches,
+                ..Default::default()
             });
         }
     }
@@ -157,36 +234,79 @@ pub async fn get_game_state(state: State<'_, AppState>) -> Result<GameState, Str
         let was_in_game = *state.in_game_session.read();
         if was_in_game {
             // Returning to lobby - clear all caches for next game
-            state.cached_parties.write().clear();
-            state.fetched_history_players.write().clear();
+            state.parties.clear();
+            state.history_fetched.clear();
             *state.in_game_session.write() = false;
         }
     }
 
+    // Check matchmaking queue
+    if let Some(party_id) = api.get_party_id().await {
+        if let Ok(party_data) = api.get_party(&party_id).await {
+            if party::is_queuing(&party_data) {
+                let mode_name = party_data
+                    .matchmaking_data
+                    .and_then(|m| m.queue_id)
+                    .map(|q| QUEUE_NAMES.get(q.as_str()).map(|s| s.to_string()).unwrap_or(q));
+
+                return Ok(GameState {
+                    state: "queuing".into(),
+                    mode_name,
+                    queue_elapsed_secs: party_data
+                        .queue_entry_time
+                        .as_deref()
+                        .and_then(party::queue_elapsed_secs),
+                    ..Default::default()
+                });
+            }
+        }
+    }
+
     Ok(GameState {
         state: "idle".into(),
-        match_id: None,
-        map_name: None,
-        mode_name: None,
-        side: None,
-        allies: vec![],
-        enemies: vec![],
+        ..Default::default()
     })
 }
 
+/// Lookups already cached for a roster
+struct RosterInfo {
+    names: HashMap<String, String>,
+    parties: HashMap<String, String>,
+    ranks: HashMap<String, RankInfo>,
+    stats: HashMap<String, PlayerStats>,
+}
+
+/// Names, parties, ranks and stats already cached for a roster - never hits the network
+fn cached_roster_info(state: &AppState, match_id: &str, puuids: &[String]) -> RosterInfo {
+    // Ranks and stats from a previous match are out of date
+    let ranks = if state.ranks.in_scope(match_id) {
+        state.ranks.get_many(puuids)
+    } else {
+        HashMap::new()
+    };
+    let stats = if state.stats.in_scope(match_id) {
+        state.stats.get_many(puuids)
+    } else {
+        HashMap::new()
+    };
+    RosterInfo {
+        names: state.names.get_many(puuids),
+        parties: state.parties.get_many(puuids),
+        ranks,
+        stats,
+    }
+}
+
 /// Get parties with caching - persists across pregame->ingame transition
 /// Only clears when returning to idle state (lobby)
-async fn get_cached_parties(
-    state: &State<'_, AppState>,
-    _match_id: &str,
-    puuids: &[String],
-    api: &crate::api::ValorantAPI,
-) -> HashMap<String, String> {
+pub async fn get_cached_parties(state: &AppState, puuids: &[String]) -> HashMap<String, String> {
+    let api = &state.api;
+
     // Mark that we're in a game session
     *state.in_game_session.write() = true;
 
     // Get existing cached parties
-    let cached = state.cached_parties.read().clone();
+    let cached = state.parties.entries();
 
     // Check if all players are already cached
     let all_cached = puuids.iter().all(|p| cached.contains_key(p));
@@ -195,13 +315,10 @@ async fn get_cached_parties(
     }
 
     // Determine which players need history fetch (not fetched before this game session)
-    let players_needing_fetch: Vec<String> = {
-        let fetched = state.fetched_history_players.read();
-        puuids.iter()
-            .filter(|p| !fetched.contains(*p))
-            .cloned()
-            .collect()
-    };
+    let players_needing_fetch: Vec<String> = puuids.iter()
+        .filter(|p| !state.history_fetched.contains(p))
+        .cloned()
+        .collect();
 
     // If no new players to fetch, return existing cache + mark missing as Solo
     if players_needing_fetch.is_empty() {
@@ -219,30 +336,295 @@ async fn get_cached_parties(
     let new_parties = api.detect_parties_with_cache(puuids, &players_needing_fetch, &cached).await;
 
     // Mark these players as fetched
-    {
-        let mut fetched = state.fetched_history_players.write();
-        for p in &players_needing_fetch {
-            fetched.insert(p.clone());
-        }
-    }
+    state.history_fetched.insert_many(players_needing_fetch.into_iter().map(|p| (p, ())));
 
     // Update party cache with merged result
-    *state.cached_parties.write() = new_parties.clone();
+    state.parties.insert_many(new_parties.clone());
 
     new_parties
 }
 
+/// Get owned agent ids, fetching once per session
+pub async fn get_cached_owned_agents(state: &AppState) -> Option<HashSet<String>> {
+    if let Some(owned) = state.owned_agents.read().clone() {
+        return Some(owned);
+    }
+
+    let owned = state.api.get_owned_agents().await?;
+    *state.owned_agents.write() = Some(owned.clone());
+    Some(owned)
+}
+
+/// Get the content catalog, fetching once per session
+const CONTENT_KEY: &str = "catalog";
+
+pub async fn get_cached_content(state: &AppState) -> Option<Arc<ContentCatalog>> {
+    // A new client version can ship new skins, so the catalog is refetched
+    state.content.set_scope(&state.api.client_version());
+    if let Some(catalog) = state.content.get(CONTENT_KEY) {
+        return Some(catalog);
+    }
+
+    let catalog = Arc::new(state.api.get_content_catalog().await?);
+    state.content.insert(CONTENT_KEY, catalog.clone());
+    Some(catalog)
+}
+
+/// Current rank and RR per player, one MMR request per uncached player in parallel
+pub async fn get_cached_ranks(state: &AppState, match_id: &str, puuids: &[String]) -> HashMap<String, RankInfo> {
+    state.ranks.set_scope(match_id);
+    let mut ranks = state.ranks.get_many(puuids);
+    let missing: Vec<String> = puuids.iter().filter(|p| !ranks.contains_key(*p)).cloned().collect();
+    if !missing.is_empty() {
+        let api = &state.api;
+        let fetched = lookup::fetch_each(missing, |puuid| async move {
+            let (tier, rr) = api.get_player_mmr(&puuid).await?;
+            Some(RankInfo { tier: tier as i32, rr: rr as i32 })
+        })
+        .await;
+        state.ranks.insert_many(fetched.clone());
+        ranks.extend(fetched);
+    }
+    ranks
+}
+
+/// Recent match totals per player - a history request plus a few match details each
+pub async fn get_cached_stats(
+    state: &AppState,
+    match_id: &str,
+    puuids: &[String],
+) -> HashMap<String, PlayerStats> {
+    state.stats.set_scope(match_id);
+    let mut stats = state.stats.get_many(puuids);
+    let missing: Vec<String> = puuids.iter().filter(|p| !stats.contains_key(*p)).cloned().collect();
+    if !missing.is_empty() {
+        let api = &state.api;
+        let fetched = lookup::fetch_each(missing, |puuid| async move {
+            api.get_player_stats(&puuid, stats::RECENT_MATCHES).await
+        })
+        .await;
+        state.stats.insert_many(fetched.clone());
+        stats.extend(fetched);
+    }
+    stats
+}
+
+/// Names for the given players, only looking up the ones not cached yet
+pub async fn get_cached_names(state: &AppState, puuids: &[String]) -> HashMap<String, String> {
+    let mut names = state.names.get_many(puuids);
+    let missing: Vec<String> = puuids.iter().filter(|p| !names.contains_key(*p)).cloned().collect();
+    if !missing.is_empty() {
+        let fetched = state.api.get_player_names(&missing).await;
+        state.names.insert_many(fetched.clone());
+        names.extend(fetched);
+    }
+    names
+}
+
+/// Get names of agents the account owns - for the agent picker
+#[tauri::command]
+pub async fn get_owned_agents(state: State<'_, AppState>) -> Result<Vec<String>, String> {
+    if !*state.api.connected.read() {
+        return Err("Not connected".into());
+    }
+
+    let owned = get_cached_owned_agents(&state)
+        .await
+        .ok_or_else(|| "Failed to fetch owned agents".to_string())?;
+
+    let mut names: Vec<String> = AGENTS
+        .iter()
+        .filter(|(_, id)| owned.contains(**id))
+        .map(|(name, _)| name.to_string())
+        .collect();
+    names.sort();
+    Ok(names)
+}
+
+/// Hover an agent in agent select without locking it
+#[tauri::command]
+pub async fn hover_agent(state: State<'_, AppState>, agent: String) -> Result<(), String> {
+    let api = &state.api;
+    let agent_id = AGENTS
+        .get(agent.to_lowercase().as_str())
+        .ok_or_else(|| format!("Unknown agent: {}", agent))?;
+
+    if let Some(owned) = get_cached_owned_agents(&state).await {
+        if !owned.contains(*agent_id) {
+            return Err(format!("Agent not owned: {}", agent));
+        }
+    }
+
+    let match_id = api.get_pregame_match_id().await.ok_or("Not in agent select")?;
+    api.select_agent(&match_id, agent_id)
+        .await
+        .map(|_| ())
+        .map_err(|e| e.to_string())
+}
+
+/// Quit the current pregame match (dodge)
+#[tauri::command]
+pub async fn dodge_match(state: State<'_, AppState>) -> Result<(), String> {
+    let api = &state.api;
+    let match_id = api.get_pregame_match_id().await.ok_or("Not in agent select")?;
+    api.quit_pregame(&match_id).await.map_err(|e| e.to_string())
+}
+
+/// Role coverage of my team in agent select
+#[tauri::command]
+pub async fn get_team_composition(state: State<'_, AppState>) -> Result<TeamComposition, String> {
+    let api = &state.api;
+    let match_id = api.get_pregame_match_id().await.ok_or("Not in agent select")?;
+    let match_data = api
+        .get_pregame_match(&match_id)
+        .await
+        .ok_or("Failed to fetch pregame match")?;
+    let team = match_data.ally_team.ok_or("No ally team")?;
+    Ok(composition::analyze(&team))
+}
+
 #[tauri::command]
-pub fn set_auto_lock(state: State<'_, AppState>, agent: Option<String>) {
-    *state.auto_lock_agent.write() = agent;
+pub fn set_auto_lock(app: AppHandle, state: State<'_, AppState>, agent: Option<String>) {
+    let updated = state.settings.modify(|s| {
+        // Picking an agent switches a tray-disabled auto-lock back on
+        s.auto_lock_enabled |= agent.is_some();
+        s.auto_lock_agent = agent;
+    });
+    settings::changed(&app, &updated);
 }
 
 #[tauri::command]
 pub fn get_auto_lock(state: State<'_, AppState>) -> Option<String> {
-    state.auto_lock_agent.read().clone()
+    state.settings.get().auto_lock().map(String::from)
+}
+
+#[tauri::command]
+pub fn get_settings(state: State<'_, AppState>) -> Settings {
+    state.settings.get()
+}
+
+/// Apply a partial settings object, e.g. `{ "auto_lock_agent": "Jett" }`
+#[tauri::command]
+pub fn update_settings(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    patch: serde_json::Value,
+) -> Result<Settings, String> {
+    let previous = state.settings.get();
+    let mut updated = state.settings.merged(patch)?;
+
+    // Same checks as the dedicated commands, before anything is stored
+    if updated.log_level != previous.log_level && !logging::LOG_LEVELS.contains(&updated.log_level.as_str()) {
+        return Err(format!("Unknown log level: {}", updated.log_level));
+    }
+    for action in HotkeyAction::ALL {
+        if action.binding(&updated.hotkeys) == action.binding(&previous.hotkeys) {
+            continue;
+        }
+        if let Some(error) = hotkeys::conflict(&updated.hotkeys, action) {
+            return Err(error.message);
+        }
+    }
+    overlay::clamp(&mut updated.overlay);
+    state.settings.set(updated.clone());
+
+    if updated.log_level != previous.log_level {
+        logging::set_level(&updated.log_level)?;
+    }
+    if updated.hotkeys != previous.hotkeys {
+        hotkeys::register_all(&app);
+    }
+    settings::changed(&app, &updated);
+    if updated.overlay != previous.overlay {
+        hud::sync(&app);
+    }
+    Ok(updated)
+}
+
+/// Bind an action to a new key combination, or unbind it with `null`
+#[tauri::command]
+pub fn set_hotkey(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    action: HotkeyAction,
+    binding: Option<String>,
+) -> Result<Settings, String> {
+    hotkeys::rebind(&app, action, binding).map_err(|e| e.message)?;
+    let updated = state.settings.get();
+    settings::changed(&app, &updated);
+    Ok(updated)
 }
 
-fn get_agent_name(agent_id: &str) -> String {
+/// Release every hotkey so the settings panel can record a new one
+#[tauri::command]
+pub fn pause_hotkeys(app: AppHandle) {
+    hotkeys::unregister_all(&app);
+}
+
+#[tauri::command]
+pub fn resume_hotkeys(app: AppHandle) {
+    hotkeys::register_all(&app);
+}
+
+/// Show/hide rules and opacity
+#[tauri::command]
+pub fn set_overlay_settings(app: AppHandle, overlay: OverlaySettings) -> Settings {
+    overlay::update(&app, overlay)
+}
+
+#[tauri::command]
+pub fn set_click_through(app: AppHandle, enabled: bool) {
+    overlay::set_click_through(&app, enabled);
+}
+
+#[tauri::command]
+pub fn drag_hud(app: AppHandle) -> Result<(), String> {
+    hud::start_drag(&app)
+}
+
+#[tauri::command]
+pub fn get_click_through(state: State<'_, AppState>) -> bool {
+    *state.click_through.read()
+}
+
+/// Zip logs, redacted settings and recent request summaries for a bug report
+/// and show the bundle in the file manager
+#[tauri::command]
+pub async fn export_diagnostics(app: AppHandle, state: State<'_, AppState>) -> Result<String, String> {
+    let log_dir = app.path().app_log_dir().map_err(|e| e.to_string())?;
+
+    let system = serde_json::json!({
+        "app_version": app.package_info().version.to_string(),
+        "os": std::env::consts::OS,
+        "arch": std::env::consts::ARCH,
+        "client_version": state.api.client_version(),
+        "region": *state.api.region.read(),
+        "connected": *state.api.connected.read(),
+        "phase": *state.phase.read(),
+    });
+    let extra = [
+        ("system.json", serde_json::to_string_pretty(&system)),
+        ("settings.json", serde_json::to_string_pretty(&state.settings.get())),
+        ("requests.json", serde_json::to_string_pretty(&state.api.recent_requests())),
+    ]
+    .map(|(name, json)| (name, json.unwrap_or_default()));
+
+    let path = tauri::async_runtime::spawn_blocking(move || logging::export_diagnostics(&log_dir, &extra))
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())?;
+
+    tracing::info!("Exported diagnostics to {}", path.display());
+    let _ = app.opener().reveal_item_in_dir(&path);
+    Ok(path.to_string_lossy().into_owned())
+}
+
+#[tauri::command]
+pub fn get_hotkey_errors(state: State<'_, AppState>) -> Vec<HotkeyError> {
+    state.hotkey_errors.read().clone()
+}
+
+pub fn get_agent_name(agent_id: &str) -> String {
     for (name, id) in AGENTS.iter() {
         if id.eq_ignore_ascii_case(agent_id) {
             return name.to_string();
@@ -255,118 +637,224 @@ fn get_agent_name(agent_id: &str) -> String {
 pub async fn get_player_loadout(
     state: State<'_, AppState>,
     puuid: String,
-) -> Result<Option<crate::api::types::PlayerSkinData>, String> {
+) -> Result<Option<PlayerSkinData>, String> {
     let api = &state.api;
 
     if !*api.connected.read() {
         return Err("Not connected".into());
     }
 
-    // Check cache first
-    {
-        let cached = state.cached_loadouts.read();
-        if let Some(loadout) = cached.get(&puuid) {
-            return Ok(Some(loadout.clone()));
-        }
+    // The cache is only good for the lobby I'm in now - usually filled by the match start prefetch
+    let (match_id, phase) = loadout::current_match(api).await.ok_or("Not in game")?;
+    let loaded =
+        state.loadouts.in_scope(&loadout::scope(&match_id, phase)) && !state.loadouts.entries().is_empty();
+    // A player missing from a loaded lobby isn't in it - don't refetch everyone
+    if !loaded {
+        loadout::fetch_all(&state, &match_id, phase).await;
     }
 
-    // Try to get match id - first check coregame, then pregame
-    let (match_id, is_pregame) = if let Some(id) = api.get_coregame_match_id().await {
-        (id, false)
-    } else if let Some(id) = api.get_pregame_match_id().await {
-        (id, true)
-    } else {
-        return Err("Not in game".into());
-    };
+    Ok(state.loadouts.get(&puuid))
+}
 
-    // Check if match changed - clear cache
-    {
-        let cached_match = state.loadouts_match_id.read();
-        if cached_match.as_ref() != Some(&match_id) {
-            drop(cached_match);
-            state.cached_loadouts.write().clear();
-            *state.loadouts_match_id.write() = Some(match_id.clone());
-        }
+/// Loadouts of every player in the current match
+#[tauri::command]
+pub async fn get_all_loadouts(state: State<'_, AppState>) -> Result<Vec<PlayerSkinData>, String> {
+    let api = &state.api;
+
+    if !*api.connected.read() {
+        return Err("Not connected".into());
+    }
+
+    let (match_id, phase) = loadout::current_match(api).await.ok_or("Not in game")?;
+    let mut loadouts = state.loadouts.entries();
+    if !state.loadouts.in_scope(&loadout::scope(&match_id, phase)) || loadouts.is_empty() {
+        loadout::fetch_all(&state, &match_id, phase).await;
+        loadouts = state.loadouts.entries();
     }
 
-    // Fetch loadouts based on game state
-    if is_pregame {
-        // Pregame loadouts
-        if let Some(loadouts_response) = api.get_pregame_loadouts(&match_id).await {
-            let mut cache = state.cached_loadouts.write();
+    Ok(loadouts.into_values().collect())
+}
+
+/// Daily offers, bundles and night market of the logged-in account
+#[tauri::command]
+pub async fn get_storefront(state: State<'_, AppState>) -> Result<Storefront, String> {
+    let api = &state.api;
+
+    if !*api.connected.read() {
+        return Err("Not connected".into());
+    }
 
-            for loadout_data in loadouts_response.loadouts {
-                let player_puuid = loadout_data.subject.clone();
-                let mut skins = Vec::new();
+    let raw = api.get_storefront().await.map_err(|e| e.to_string())?;
+    let catalog = get_cached_content(&state).await;
+    Ok(store::resolve_storefront(raw, catalog.as_deref()))
+}
 
-                for (weapon_id, item) in loadout_data.items {
-                    let mut chroma_id = None;
+#[tauri::command]
+pub async fn get_wallet(state: State<'_, AppState>) -> Result<Wallet, String> {
+    let api = &state.api;
 
-                    if let Some(sockets) = &item.sockets {
-                        for (_socket_id, socket_item) in sockets {
-                            if socket_item.item.type_id == "3ad1b2b2-acdb-4524-852f-954a76ddae0a" {
-                                chroma_id = Some(socket_item.item.id.clone());
-                            }
-                        }
-                    }
+    if !*api.connected.read() {
+        return Err("Not connected".into());
+    }
 
-                    skins.push(crate::api::types::WeaponSkin {
-                        weapon_id,
-                        skin_id: item.id,
-                        chroma_id,
-                    });
-                }
+    let raw = api.get_wallet().await.map_err(|e| e.to_string())?;
+    Ok(store::resolve_wallet(&raw))
+}
 
-                cache.insert(
-                    player_puuid.clone(),
-                    crate::api::types::PlayerSkinData {
-                        puuid: player_puuid,
-                        skins,
-                    },
-                );
-            }
+/// Skins the account owns, with equippable levels and chromas
+#[tauri::command]
+pub async fn get_owned_skins(state: State<'_, AppState>) -> Result<Vec<OwnedSkin>, String> {
+    let api = &state.api;
 
-            return Ok(cache.get(&puuid).cloned());
-        }
-    } else {
-        // Coregame loadouts
-        if let Some(loadouts_response) = api.get_coregame_loadouts(&match_id).await {
-            let mut cache = state.cached_loadouts.write();
-
-            for player_loadout in loadouts_response.loadouts {
-                let player_puuid = player_loadout.loadout.subject.clone();
-                let mut skins = Vec::new();
-
-                for (weapon_id, item) in player_loadout.loadout.items {
-                    let mut chroma_id = None;
-
-                    if let Some(sockets) = &item.sockets {
-                        for (_socket_id, socket_item) in sockets {
-                            if socket_item.item.type_id == "3ad1b2b2-acdb-4524-852f-954a76ddae0a" {
-                                chroma_id = Some(socket_item.item.id.clone());
-                            }
-                        }
-                    }
-
-                    skins.push(crate::api::types::WeaponSkin {
-                        weapon_id,
-                        skin_id: item.id,
-                        chroma_id,
-                    });
-                }
+    if !*api.connected.read() {
+        return Err("Not connected".into());
+    }
 
-                cache.insert(
-                    player_puuid.clone(),
-                    crate::api::types::PlayerSkinData {
-                        puuid: player_puuid,
-                        skins,
-                    },
-                );
-            }
+    let catalog = get_cached_content(&state).await.ok_or("Content catalog unavailable")?;
+    let (levels, chromas) = tokio::join!(
+        api.get_entitlements(ITEM_TYPE_SKIN_LEVELS),
+        api.get_entitlements(ITEM_TYPE_SKIN_CHROMAS),
+    );
+    let levels: HashSet<String> = levels.ok_or("Failed to fetch owned skins")?.into_iter().collect();
+    let chromas: HashSet<String> = chromas.unwrap_or_default().into_iter().collect();
 
-            return Ok(cache.get(&puuid).cloned());
-        }
+    Ok(inventory::owned_skins(&catalog, &levels, &chromas))
+}
+
+#[tauri::command]
+pub async fn get_my_loadout(state: State<'_, AppState>) -> Result<PersonalLoadout, String> {
+    if !*state.api.connected.read() {
+        return Err("Not connected".into());
+    }
+
+    state.api.get_my_loadout().await.map_err(|e| e.to_string())
+}
+
+/// Save a modified loadout - every changed gun must use a skin I own
+#[tauri::command]
+pub async fn set_my_loadout(
+    state: State<'_, AppState>,
+    loadout: PersonalLoadout,
+) -> Result<PersonalLoadout, String> {
+    let owned = get_owned_skins(state.clone()).await?;
+    let current = state.api.get_my_loadout().await.map_err(|e| e.to_string())?;
+    inventory::validate_loadout(&loadout, &current, &owned)?;
+    state.api.put_my_loadout(&loadout).await.map_err(|e| e.to_string())
+}
+
+/// Switch one weapon's skin - chroma defaults to the skin's base chroma
+#[tauri::command]
+pub async fn equip_skin(
+    state: State<'_, AppState>,
+    weapon_id: String,
+    level_id: String,
+    chroma_id: Option<String>,
+) -> Result<PersonalLoadout, String> {
+    let owned = get_owned_skins(state.clone()).await?;
+    let mut loadout = state.api.get_my_loadout().await.map_err(|e| e.to_string())?;
+    inventory::equip_skin(&mut loadout, &owned, &weapon_id, &level_id, chroma_id.as_deref())?;
+    state.api.put_my_loadout(&loadout).await.map_err(|e| e.to_string())
+}
+
+/// Resolve member names for a party response
+async fn party_info(state: &AppState, party_id: &str, raw: PartyResponse) -> PartyInfo {
+    let api = &state.api;
+    let puuids: Vec<String> = raw.members.iter().filter_map(|m| m.subject.clone()).collect();
+    let names = get_cached_names(state, &puuids).await;
+    let my_puuid = api.puuid.read().clone();
+    party::build_party_info(party_id, raw, &names, &my_puuid)
+}
+
+async fn current_party_id(state: &AppState) -> Result<String, String> {
+    if !*state.api.connected.read() {
+        return Err("Not connected".into());
+    }
+    state.api.get_party_id().await.ok_or_else(|| "Not in a party".into())
+}
+
+#[tauri::command]
+pub async fn get_party(state: State<'_, AppState>) -> Result<PartyInfo, String> {
+    let party_id = current_party_id(&state).await?;
+    let raw = state.api.get_party(&party_id).await.map_err(|e| e.to_string())?;
+    Ok(party_info(&state, &party_id, raw).await)
+}
+
+#[tauri::command]
+pub async fn set_party_queue(state: State<'_, AppState>, queue_id: String) -> Result<PartyInfo, String> {
+    let party_id = current_party_id(&state).await?;
+    let raw = state.api.set_party_queue(&party_id, &queue_id).await.map_err(|e| e.to_string())?;
+    Ok(party_info(&state, &party_id, raw).await)
+}
+
+#[tauri::command]
+pub async fn set_party_open(state: State<'_, AppState>, open: bool) -> Result<PartyInfo, String> {
+    let party_id = current_party_id(&state).await?;
+    let raw = state.api.set_party_accessibility(&party_id, open).await.map_err(|e| e.to_string())?;
+    Ok(party_info(&state, &party_id, raw).await)
+}
+
+/// Invite by Riot ID ("name#tag")
+#[tauri::command]
+pub async fn invite_to_party(state: State<'_, AppState>, riot_id: String) -> Result<PartyInfo, String> {
+    let (name, tag) = riot_id
+        .rsplit_once('#')
+        .filter(|(name, tag)| !name.is_empty() && !tag.is_empty())
+        .ok_or("Riot ID must be name#tag")?;
+
+    let party_id = current_party_id(&state).await?;
+    let raw = state.api.invite_to_party(&party_id, name, tag).await.map_err(|e| e.to_string())?;
+    Ok(party_info(&state, &party_id, raw).await)
+}
+
+#[tauri::command]
+pub async fn kick_party_member(state: State<'_, AppState>, puuid: String) -> Result<PartyInfo, String> {
+    let party_id = current_party_id(&state).await?;
+    if puuid == *state.api.puuid.read() {
+        return Err("Use leave_party to leave".into());
+    }
+
+    state.api.remove_from_party(&puuid).await.map_err(|e| e.to_string())?;
+    let raw = state.api.get_party(&party_id).await.map_err(|e| e.to_string())?;
+    Ok(party_info(&state, &party_id, raw).await)
+}
+
+#[tauri::command]
+pub async fn leave_party(state: State<'_, AppState>) -> Result<(), String> {
+    current_party_id(&state).await?;
+    let puuid = state.api.puuid.read().clone();
+    state.api.remove_from_party(&puuid).await.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn start_queue(state: State<'_, AppState>) -> Result<PartyInfo, String> {
+    let party_id = current_party_id(&state).await?;
+    let raw = state.api.join_matchmaking(&party_id).await.map_err(|e| e.to_string())?;
+    Ok(party_info(&state, &party_id, raw).await)
+}
+
+#[tauri::command]
+pub async fn stop_queue(state: State<'_, AppState>) -> Result<PartyInfo, String> {
+    let party_id = current_party_id(&state).await?;
+    let raw = state.api.leave_matchmaking(&party_id).await.map_err(|e| e.to_string())?;
+    Ok(party_info(&state, &party_id, raw).await)
+}
+
+/// Friends with what they're doing right now
+#[tauri::command]
+pub async fn get_friends(state: State<'_, AppState>) -> Result<Vec<FriendInfo>, String> {
+    let api = &state.api;
+
+    if !*api.connected.read() {
+        return Err("Not connected".into());
     }
 
-    Ok(None)
+    let (friends, presences) = tokio::join!(api.get_friends(), api.get_presence_list());
+    let friends = friends.ok_or("Failed to fetch friends")?;
+    Ok(friends::build_friend_list(friends, presences))
+}
+
+/// Servers this session's matches were played on
+#[tauri::command]
+pub fn get_server_history(state: State<'_, AppState>) -> Vec<ServerStats> {
+    server::session_stats(&state)
 }
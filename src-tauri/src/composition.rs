@@ -0,0 +1,54 @@
+use crate::api::types::{AgentRole, CompositionPick, PregameTeam, TeamComposition};
+use crate::commands::get_agent_name;
+use crate::constants::AGENT_ROLES;
+
+const ALL_ROLES: [AgentRole; 4] = [
+    AgentRole::Controller,
+    AgentRole::Duelist,
+    AgentRole::Initiator,
+    AgentRole::Sentinel,
+];
+
+/// Build role hints from ally picks - "selected" counts as hovered, "locked" as final
+pub fn analyze(team: &PregameTeam) -> TeamComposition {
+    let picks: Vec<CompositionPick> = team
+        .players
+        .iter()
+        .filter(|p| !p.character_id.is_empty() && !p.character_selection_state.is_empty())
+        .map(|p| {
+            let agent = get_agent_name(&p.character_id);
+            CompositionPick {
+                puuid: p.subject.clone(),
+                role: AGENT_ROLES.get(agent.as_str()).copied(),
+                agent,
+                locked: p.character_selection_state == "locked",
+            }
+        })
+        .collect();
+
+    let covered = |locked_only: bool| -> Vec<AgentRole> {
+        picks
+            .iter()
+            .filter(|p| p.locked || !locked_only)
+            .filter_map(|p| p.role)
+            .collect()
+    };
+    let locked_roles = covered(true);
+    let any_roles = covered(false);
+
+    let missing_roles: Vec<AgentRole> = ALL_ROLES
+        .into_iter()
+        .filter(|r| !locked_roles.contains(r))
+        .collect();
+    let hovered_roles = missing_roles
+        .iter()
+        .copied()
+        .filter(|r| any_roles.contains(r))
+        .collect();
+
+    TeamComposition {
+        picks,
+        missing_roles,
+        hovered_roles,
+    }
+}
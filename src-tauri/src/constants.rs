@@ -1,5 +1,28 @@
 use std::collections::HashMap;
 use once_cell::sync::Lazy;
+use crate::api::types::AgentRole;
+
+/// Store entitlement item type for agents
+pub const ITEM_TYPE_AGENTS: &str = "01bb38e1-da47-4e6a-9b3d-945fe4655707";
+
+/// Wallet currency ids
+pub const CURRENCY_VP: &str = "85ad13f7-3d1b-5128-9eb2-7cd8ee0b5741";
+pub const CURRENCY_RADIANITE: &str = "e59aa87c-4cbf-517a-5983-6e81511be9b7";
+pub const CURRENCY_KINGDOM_CREDITS: &str = "85ca954a-41f2-ce94-9b45-8ca3dd39a00d";
+
+/// Loadout socket ids - keys of a weapon's `Sockets` map
+pub const SOCKET_SKIN: &str = "bcef87d6-209b-46c6-8b19-fbe40bd95abc";
+pub const SOCKET_SKIN_LEVEL: &str = "e7c63390-eda7-46e0-bb7a-a6abdacd2433";
+pub const SOCKET_SKIN_CHROMA: &str = "3ad1b2b2-acdb-4524-852f-954a76ddae0a";
+pub const SOCKET_BUDDY: &str = "77258665-71d1-4623-bc72-44db9bd5b3b3";
+pub const SOCKET_BUDDY_LEVEL: &str = "dd3bf334-87f3-40bd-b043-682a57a8dc3a";
+
+/// Store entitlement item types for skin levels and chromas
+pub const ITEM_TYPE_SKIN_LEVELS: &str = "e7c63390-eda7-46e0-bb7a-a6abdacd2433";
+pub const ITEM_TYPE_SKIN_CHROMAS: &str = "3ad1b2b2-acdb-4524-852f-954a76ddae0a";
+
+/// Agents every account owns - these never show up in entitlements
+pub const DEFAULT_AGENTS: [&str; 5] = ["jett", "phoenix", "sova", "sage", "brimstone"];
 
 pub static AGENTS: Lazy<HashMap<&'static str, &'static str>> = Lazy::new(|| {
     let mut m = HashMap::new();
@@ -31,6 +54,24 @@ pub static AGENTS: Lazy<HashMap<&'static str, &'static str>> = Lazy::new(|| {
     m
 });
 
+pub static AGENT_ROLES: Lazy<HashMap<&'static str, AgentRole>> = Lazy::new(|| {
+    use AgentRole::*;
+    let mut m = HashMap::new();
+    for name in ["jett", "reyna", "raze", "phoenix", "yoru", "neon", "iso"] {
+        m.insert(name, Duelist);
+    }
+    for name in ["breach", "sova", "skye", "kayo", "fade", "gekko"] {
+        m.insert(name, Initiator);
+    }
+    for name in ["brimstone", "viper", "omen", "astra", "harbor", "clove"] {
+        m.insert(name, Controller);
+    }
+    for name in ["sage", "cypher", "killjoy", "chamber", "deadlock", "vyse"] {
+        m.insert(name, Sentinel);
+    }
+    m
+});
+
 pub static MAP_NAMES: Lazy<HashMap<&'static str, &'static str>> = Lazy::new(|| {
     let mut m = HashMap::new();
     m.insert("/Game/Maps/Ascent/Ascent", "Ascent");
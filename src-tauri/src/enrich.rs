@@ -0,0 +1,98 @@
+use crate::api::types::{Enrichment, EnrichmentEvent};
+use crate::commands::{get_cached_names, get_cached_parties, get_cached_ranks, get_cached_stats};
+use crate::loadout::LoadoutPhase;
+use crate::state::AppState;
+use std::future::Future;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter, Manager};
+
+// Retry an incomplete roster at most this often
+const RETRY_INTERVAL: Duration = Duration::from_secs(5);
+// Names, parties, ranks and stats
+const STEPS: usize = 4;
+
+/// Match/phase the roster enrichment tasks were last started for
+pub struct EnrichmentRound {
+    match_id: String,
+    phase: LoadoutPhase,
+    started: Instant,
+    // Steps that haven't emitted yet
+    running: Arc<AtomicUsize>,
+}
+
+/// Start the roster lookups for a match - once per match id and phase, or again
+/// after RETRY_INTERVAL while the cached roster is still `incomplete`
+/// and the previous round has finished
+/// Each lookup runs on its own so a slow pd endpoint doesn't hold back the others
+pub fn trigger(app: &AppHandle, match_id: &str, phase: LoadoutPhase, puuids: Vec<String>, incomplete: bool) {
+    let state = app.state::<AppState>();
+    let running = Arc::new(AtomicUsize::new(STEPS));
+    {
+        let mut last = state.enrichment_started.write();
+        if let Some(round) = last.as_ref() {
+            let same = round.match_id == match_id && round.phase == phase;
+            let busy = round.running.load(Ordering::SeqCst) > 0;
+            if same && (!incomplete || busy || round.started.elapsed() < RETRY_INTERVAL) {
+                return;
+            }
+        }
+        *last = Some(EnrichmentRound {
+            match_id: match_id.to_string(),
+            phase,
+            started: Instant::now(),
+            running: running.clone(),
+        });
+    }
+
+    let names_for = puuids.clone();
+    spawn_step(app, match_id, &running, |app| async move {
+        let names = get_cached_names(&app.state::<AppState>(), &names_for).await;
+        Enrichment::Names { names }
+    });
+
+    let parties_for = puuids.clone();
+    spawn_step(app, match_id, &running, |app| async move {
+        let parties = get_cached_parties(&app.state::<AppState>(), &parties_for).await;
+        Enrichment::Parties { parties }
+    });
+
+    let ranks_for = puuids.clone();
+    let ranks_match = match_id.to_string();
+    spawn_step(app, match_id, &running, |app| async move {
+        let ranks = get_cached_ranks(&app.state::<AppState>(), &ranks_match, &ranks_for).await;
+        Enrichment::Ranks { ranks }
+    });
+
+    // History pass - the slowest step, so it arrives last
+    let stats_match = match_id.to_string();
+    spawn_step(app, match_id, &running, |app| async move {
+        let stats = get_cached_stats(&app.state::<AppState>(), &stats_match, &puuids).await;
+        Enrichment::Stats { stats }
+    });
+}
+
+/// Emit a finished lookup tagged with its match id
+pub fn emit(app: &AppHandle, match_id: &str, data: Enrichment) {
+    let event = EnrichmentEvent {
+        match_id: match_id.to_string(),
+        data,
+    };
+    let _ = app.emit("game-state-enrichment", event);
+}
+
+fn spawn_step<F, Fut>(app: &AppHandle, match_id: &str, running: &Arc<AtomicUsize>, step: F)
+where
+    F: FnOnce(AppHandle) -> Fut + Send + 'static,
+    Fut: Future<Output = Enrichment> + Send + 'static,
+{
+    let app = app.clone();
+    let match_id = match_id.to_string();
+    let running = running.clone();
+    tauri::async_runtime::spawn(async move {
+        let data = step(app.clone()).await;
+        emit(&app, &match_id, data);
+        running.fetch_sub(1, Ordering::SeqCst);
+    });
+}
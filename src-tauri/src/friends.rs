@@ -0,0 +1,55 @@
+use crate::api::presence::presence_details;
+use crate::api::types::{Friend, FriendInfo, Presence};
+use std::collections::HashMap;
+
+/// Join friends with their presences - sorted in-game first, offline last
+pub fn build_friend_list(friends: Vec<Friend>, presences: Vec<Presence>) -> Vec<FriendInfo> {
+    let presences: HashMap<String, Presence> =
+        presences.into_iter().map(|p| (p.puuid.clone(), p)).collect();
+
+    let mut list: Vec<FriendInfo> = friends
+        .into_iter()
+        .map(|f| {
+            let presence = presences.get(&f.puuid);
+            let details = presence.and_then(presence_details);
+            let status = match (&presence, &details) {
+                (None, _) => "offline",
+                (Some(_), None) => "online",
+                (_, Some(d)) if d.session_loop_state == "INGAME" => "ingame",
+                (_, Some(d)) if d.session_loop_state == "PREGAME" => "pregame",
+                (_, Some(d)) if d.is_queuing => "queuing",
+                _ => "menus",
+            };
+
+            let name = match (f.game_name, f.game_tag) {
+                (Some(name), Some(tag)) if !tag.is_empty() => format!("{}#{}", name, tag),
+                (Some(name), _) => name,
+                _ => "Unknown".into(),
+            };
+
+            FriendInfo {
+                puuid: f.puuid,
+                name,
+                note: f.note.filter(|n| !n.is_empty()),
+                status: status.into(),
+                availability: presence.and_then(|p| p.state.clone()),
+                presence: details,
+            }
+        })
+        .collect();
+
+    let order = |status: &str| match status {
+        "ingame" => 0,
+        "pregame" => 1,
+        "queuing" => 2,
+        "menus" => 3,
+        "online" => 4,
+        _ => 5,
+    };
+    list.sort_by(|a, b| {
+        order(&a.status)
+            .cmp(&order(&b.status))
+            .then_with(|| a.name.to_lowercase().cmp(&b.name.to_lowercase()))
+    });
+    list
+}
@@ -0,0 +1,87 @@
+use crate::api::types::CoregameMatch;
+use crate::constants::QUEUE_NAMES;
+
+const RANGE_MAP_ID: &str = "/Game/Maps/Poveglia/Range";
+
+/// Queue, mode and provisioning details of a running match
+#[derive(Debug, Clone, Default)]
+pub struct MatchMode {
+    pub queue_id: Option<String>,
+    pub mode_id: Option<String>,
+    pub mode_name: Option<String>,
+    pub is_ranked: bool,
+    pub is_custom: bool,
+    pub is_range: bool,
+}
+
+impl MatchMode {
+    pub fn from_coregame(match_data: &CoregameMatch) -> Self {
+        let flow = match_data.provisioning_flow.as_deref().unwrap_or_default();
+        let is_range = flow == "ShootingRange" || match_data.map_id == RANGE_MAP_ID;
+        let is_custom = flow == "CustomGame";
+
+        // Custom games and the Range report an empty queue id
+        let queue_id = match_data
+            .matchmaking_data
+            .as_ref()
+            .and_then(|m| m.queue_id.clone())
+            .filter(|q| !q.is_empty());
+
+        let mode_name = if is_range {
+            Some("The Range".to_string())
+        } else if let Some(queue) = queue_id.as_deref() {
+            Some(QUEUE_NAMES.get(queue).map(|s| s.to_string()).unwrap_or_else(|| queue.to_string()))
+        } else if is_custom {
+            let custom = QUEUE_NAMES.get("custom").copied().unwrap_or("Custom");
+            let mode = match_data.mode_id.as_deref().and_then(mode_name_from_id);
+            Some(match mode {
+                Some(mode) => format!("{} ({})", custom, mode),
+                None => custom.to_string(),
+            })
+        } else {
+            match_data.mode_id.as_deref().and_then(mode_name_from_id).map(String::from)
+        };
+
+        Self {
+            queue_id,
+            mode_id: match_data.mode_id.clone(),
+            mode_name,
+            is_ranked: match_data.matchmaking_data.as_ref().is_some_and(|m| m.is_ranked),
+            is_custom,
+            is_range,
+        }
+    }
+
+    /// Queue id used for per-mode rules, custom games fall back to their mode
+    pub fn rules_queue(&self) -> Option<&str> {
+        self.queue_id
+            .as_deref()
+            .or_else(|| self.mode_id.as_deref().and_then(queue_from_mode_id))
+    }
+}
+
+/// "/Game/GameModes/Deathmatch/DeathmatchGameMode.DeathmatchGameMode_C" -> "Deathmatch"
+fn mode_folder(mode_id: &str) -> Option<&str> {
+    mode_id.strip_prefix("/Game/GameModes/")?.split('/').next()
+}
+
+fn queue_from_mode_id(mode_id: &str) -> Option<&'static str> {
+    match mode_folder(mode_id)? {
+        "Bomb" => Some("unrated"),
+        "QuickBomb" => Some("spikerush"),
+        "Deathmatch" => Some("deathmatch"),
+        "GunGame" => Some("ggteam"),
+        "OneForAll" => Some("onefa"),
+        "HURM" => Some("hurm"),
+        "SwiftPlay" => Some("swiftplay"),
+        _ => None,
+    }
+}
+
+fn mode_name_from_id(mode_id: &str) -> Option<&'static str> {
+    match queue_from_mode_id(mode_id)? {
+        // Turkish like the queue names - a custom Bomb game isn't "Normal"
+        "unrated" => Some("Standart"),
+        queue => QUEUE_NAMES.get(queue).copied(),
+    }
+}
@@ -0,0 +1,202 @@
+use crate::overlay;
+use crate::settings::Hotkeys;
+use crate::state::AppState;
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Emitter, Manager};
+use tauri_plugin_global_shortcut::{GlobalShortcutExt, Shortcut, ShortcutEvent, ShortcutState};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HotkeyAction {
+    ToggleOverlay,
+    ToggleClickThrough,
+    RefreshState,
+    CyclePanels,
+}
+
+impl HotkeyAction {
+    pub const ALL: [HotkeyAction; 4] = [
+        HotkeyAction::ToggleOverlay,
+        HotkeyAction::ToggleClickThrough,
+        HotkeyAction::RefreshState,
+        HotkeyAction::CyclePanels,
+    ];
+
+    pub fn binding(self, hotkeys: &Hotkeys) -> Option<&str> {
+        let binding = match self {
+            HotkeyAction::ToggleOverlay => &hotkeys.toggle_overlay,
+            HotkeyAction::ToggleClickThrough => &hotkeys.toggle_click_through,
+            HotkeyAction::RefreshState => &hotkeys.refresh_state,
+            HotkeyAction::CyclePanels => &hotkeys.cycle_panels,
+        };
+        binding.as_deref().filter(|b| !b.is_empty())
+    }
+
+    pub fn set_binding(self, hotkeys: &mut Hotkeys, binding: Option<String>) {
+        let slot = match self {
+            HotkeyAction::ToggleOverlay => &mut hotkeys.toggle_overlay,
+            HotkeyAction::ToggleClickThrough => &mut hotkeys.toggle_click_through,
+            HotkeyAction::RefreshState => &mut hotkeys.refresh_state,
+            HotkeyAction::CyclePanels => &mut hotkeys.cycle_panels,
+        };
+        *slot = binding;
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            HotkeyAction::ToggleOverlay => "toggle overlay",
+            HotkeyAction::ToggleClickThrough => "toggle click-through",
+            HotkeyAction::RefreshState => "refresh state",
+            HotkeyAction::CyclePanels => "cycle panels",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HotkeyErrorKind {
+    // Not a key combination the OS understands
+    Invalid,
+    // Same keys as another action
+    Conflict,
+    // The OS refused it, usually because another app holds it
+    Unavailable,
+}
+
+/// Sent as "hotkey-error" when a binding can't be registered
+#[derive(Debug, Clone, Serialize)]
+pub struct HotkeyError {
+    pub action: HotkeyAction,
+    pub binding: String,
+    pub kind: HotkeyErrorKind,
+    pub message: String,
+}
+
+/// Register every binding from the settings, replacing whatever was registered before
+/// Failures are logged and sent to the frontend as "hotkey-error"
+/// The last failures are kept for the settings panel, which may open after startup
+pub fn register_all(app: &AppHandle) {
+    let state = app.state::<AppState>();
+    let errors = register(app, &state.settings.get().hotkeys);
+    report(app, errors);
+}
+
+fn report(app: &AppHandle, errors: Vec<HotkeyError>) {
+    for error in &errors {
+        tracing::warn!("Hotkey {} for {}: {}", error.binding, error.action.label(), error.message);
+        let _ = app.emit("hotkey-error", error);
+    }
+    *app.state::<AppState>().hotkey_errors.write() = errors;
+}
+
+/// Unregister everything, e.g. while the settings panel records a new key
+pub fn unregister_all(app: &AppHandle) {
+    if let Err(e) = app.global_shortcut().unregister_all() {
+        tracing::warn!("Failed to unregister hotkeys: {}", e);
+    }
+}
+
+fn register(app: &AppHandle, hotkeys: &Hotkeys) -> Vec<HotkeyError> {
+    unregister_all(app);
+
+    let mut errors = Vec::new();
+    let mut taken: Vec<(Shortcut, HotkeyAction)> = Vec::new();
+
+    for action in HotkeyAction::ALL {
+        let Some(binding) = action.binding(hotkeys) else { continue };
+        let error = |kind, message: String| HotkeyError {
+            action,
+            binding: binding.to_string(),
+            kind,
+            message,
+        };
+
+        let shortcut = match binding.parse::<Shortcut>() {
+            Ok(shortcut) => shortcut,
+            Err(e) => {
+                errors.push(error(HotkeyErrorKind::Invalid, e.to_string()));
+                continue;
+            }
+        };
+
+        // "Ctrl+F2" and "Control+F2" parse to the same shortcut
+        if let Some((_, other)) = taken.iter().find(|(s, _)| *s == shortcut) {
+            errors.push(error(HotkeyErrorKind::Conflict, format!("Already used for {}", other.label())));
+            continue;
+        }
+
+        let handler = move |app: &AppHandle, _: &Shortcut, event: ShortcutEvent| {
+            // Released fires too, only act once per press
+            if event.state() == ShortcutState::Pressed {
+                run(app, action);
+            }
+        };
+        match app.global_shortcut().on_shortcut(shortcut, handler) {
+            Ok(()) => taken.push((shortcut, action)),
+            Err(e) => errors.push(error(HotkeyErrorKind::Unavailable, e.to_string())),
+        }
+    }
+    errors
+}
+
+/// Change one binding, keeping the old one if the new keys can't be registered
+pub fn rebind(app: &AppHandle, action: HotkeyAction, binding: Option<String>) -> Result<Hotkeys, HotkeyError> {
+    let state = app.state::<AppState>();
+    let previous = state.settings.get().hotkeys;
+
+    let mut hotkeys = previous.clone();
+    action.set_binding(&mut hotkeys, binding);
+
+    // register() gives a shared shortcut to whichever action comes first,
+    // check up front so the conflict is always reported for the one being changed
+    let mut errors = match conflict(&hotkeys, action) {
+        Some(error) => vec![error],
+        None => register(app, &hotkeys),
+    };
+    if let Some(i) = errors.iter().position(|e| e.action == action) {
+        // Hotkeys may be paused while a key is recorded, put the old ones back either way
+        let error = errors.remove(i);
+        report(app, register(app, &previous));
+        return Err(error);
+    }
+
+    report(app, errors);
+    state.settings.modify(|s| s.hotkeys = hotkeys.clone());
+    Ok(hotkeys)
+}
+
+/// The other action already bound to the same shortcut as `action`, if any
+pub fn conflict(hotkeys: &Hotkeys, action: HotkeyAction) -> Option<HotkeyError> {
+    let binding = action.binding(hotkeys)?;
+    let shortcut = binding.parse::<Shortcut>().ok()?;
+
+    let other = HotkeyAction::ALL.into_iter().filter(|&other| other != action).find(|other| {
+        other.binding(hotkeys).and_then(|b| b.parse::<Shortcut>().ok()) == Some(shortcut)
+    })?;
+    Some(HotkeyError {
+        action,
+        binding: binding.to_string(),
+        kind: HotkeyErrorKind::Conflict,
+        message: format!("Already used for {}", other.label()),
+    })
+}
+
+fn run(app: &AppHandle, action: HotkeyAction) {
+    tracing::debug!("Hotkey: {}", action.label());
+
+    match action {
+        HotkeyAction::ToggleOverlay => overlay::toggle_visible(app),
+        HotkeyAction::ToggleClickThrough => overlay::toggle_click_through(app),
+        HotkeyAction::RefreshState => {
+            // Let the next poll start enrichment and loadout prefetches again
+            let state = app.state::<AppState>();
+            *state.enrichment_started.write() = None;
+            *state.loadouts_prefetched.write() = None;
+            let _ = app.emit("hotkey", action);
+        }
+        // Panels live in the frontend
+        HotkeyAction::CyclePanels => {
+            let _ = app.emit("hotkey", action);
+        }
+    }
+}
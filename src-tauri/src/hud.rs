@@ -0,0 +1,43 @@
+use crate::state::AppState;
+use tauri::{AppHandle, Manager, WebviewUrl, WebviewWindowBuilder};
+
+pub const LABEL: &str = "hud";
+// One row of five enemies
+const WIDTH: f64 = 560.0;
+const HEIGHT: f64 = 64.0;
+
+/// Create the compact in-game HUD - it loads the same frontend, which renders
+/// the HUD layout for this window label
+pub fn create(app: &AppHandle) -> tauri::Result<()> {
+    WebviewWindowBuilder::new(app, LABEL, WebviewUrl::default())
+        .title("VALORANT Tracker HUD")
+        .inner_size(WIDTH, HEIGHT)
+        .resizable(false)
+        .decorations(false)
+        .transparent(true)
+        .always_on_top(true)
+        .skip_taskbar(true)
+        .shadow(false)
+        .focused(false)
+        .visible(false)
+        .build()?;
+    Ok(())
+}
+
+/// Show the HUD only while a match is running, if the user wants it
+pub fn sync(app: &AppHandle) {
+    let Some(window) = app.get_webview_window(LABEL) else { return };
+    let state = app.state::<AppState>();
+    let visible = state.settings.get().overlay.show_hud && *state.phase.read() == "ingame";
+
+    if window.is_visible().unwrap_or(false) != visible {
+        // Never focus it - it sits on top of the game
+        let _ = if visible { window.show() } else { window.hide() };
+    }
+}
+
+/// Move the HUD with the mouse - done here since the HUD's capability has no window permissions
+pub fn start_drag(app: &AppHandle) -> Result<(), String> {
+    let window = app.get_webview_window(LABEL).ok_or("HUD window not found")?;
+    window.start_dragging().map_err(|e| e.to_string())
+}
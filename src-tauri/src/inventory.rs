@@ -0,0 +1,127 @@
+use crate::api::content::ContentCatalog;
+use crate::api::types::{OwnedSkin, PersonalLoadout};
+use std::collections::HashSet;
+
+/// Group owned level and chroma entitlements by skin
+/// Default skins, first levels and base chromas are owned without an entitlement
+pub fn owned_skins(
+    catalog: &ContentCatalog,
+    owned_levels: &HashSet<String>,
+    owned_chromas: &HashSet<String>,
+) -> Vec<OwnedSkin> {
+    let mut skins: Vec<OwnedSkin> = catalog
+        .skin_variants
+        .iter()
+        .filter_map(|(skin_id, variants)| {
+            let is_default = catalog.default_skins.contains(skin_id);
+            let levels: Vec<String> = variants
+                .levels
+                .iter()
+                .enumerate()
+                .filter(|(i, id)| owned_levels.contains(*id) || (is_default && *i == 0))
+                .map(|(_, id)| id.clone())
+                .collect();
+            if levels.is_empty() {
+                return None;
+            }
+
+            let chromas = variants
+                .chromas
+                .iter()
+                .enumerate()
+                .filter(|(i, id)| *i == 0 || owned_chromas.contains(*id))
+                .map(|(_, id)| id.clone())
+                .collect();
+
+            Some(OwnedSkin {
+                weapon_id: variants.weapon_id.clone(),
+                weapon_name: catalog.weapon_name(&variants.weapon_id),
+                skin_id: skin_id.clone(),
+                name: catalog.skin(skin_id).map(|s| s.name.clone()).unwrap_or_default(),
+                tier: catalog.tier_name(skin_id),
+                levels,
+                chromas,
+            })
+        })
+        .collect();
+
+    skins.sort_by(|a, b| (&a.weapon_name, &a.name).cmp(&(&b.weapon_name, &b.name)));
+    skins
+}
+
+/// Owned skin with this level on the weapon, and the chroma to use - the base one if None
+fn owned_variant<'a>(
+    owned: &'a [OwnedSkin],
+    weapon_id: &str,
+    level_id: &str,
+    chroma_id: Option<&str>,
+) -> Result<(&'a OwnedSkin, String), String> {
+    let level_id = level_id.to_lowercase();
+    let skin = owned
+        .iter()
+        .find(|s| s.weapon_id.eq_ignore_ascii_case(weapon_id) && s.levels.contains(&level_id))
+        .ok_or("Skin level not owned for this weapon")?;
+
+    let chroma_id = match chroma_id {
+        Some(id) => {
+            let id = id.to_lowercase();
+            if !skin.chromas.contains(&id) {
+                return Err("Chroma not owned".into());
+            }
+            id
+        }
+        None => skin.chromas.first().cloned().ok_or("Skin has no chromas")?,
+    };
+    Ok((skin, chroma_id))
+}
+
+/// Check a loadout from the frontend before it's saved - every gun must be one
+/// I have and either unchanged or set to a skin level and chroma I own
+pub fn validate_loadout(
+    loadout: &PersonalLoadout,
+    current: &PersonalLoadout,
+    owned: &[OwnedSkin],
+) -> Result<(), String> {
+    for gun in &loadout.guns {
+        let before = current
+            .guns
+            .iter()
+            .find(|g| g.id.eq_ignore_ascii_case(&gun.id))
+            .ok_or("Weapon not in loadout")?;
+        let unchanged = before.skin_id.eq_ignore_ascii_case(&gun.skin_id)
+            && before.skin_level_id.eq_ignore_ascii_case(&gun.skin_level_id)
+            && before.chroma_id.eq_ignore_ascii_case(&gun.chroma_id);
+        if unchanged {
+            continue;
+        }
+
+        let (skin, _) = owned_variant(owned, &gun.id, &gun.skin_level_id, Some(&gun.chroma_id))?;
+        if !skin.skin_id.eq_ignore_ascii_case(&gun.skin_id) {
+            return Err("Skin level doesn't belong to the skin".into());
+        }
+    }
+    Ok(())
+}
+
+/// Put a skin level/chroma on a weapon in my loadout - checked against what I own
+pub fn equip_skin(
+    loadout: &mut PersonalLoadout,
+    owned: &[OwnedSkin],
+    weapon_id: &str,
+    level_id: &str,
+    chroma_id: Option<&str>,
+) -> Result<(), String> {
+    let (skin, chroma_id) = owned_variant(owned, weapon_id, level_id, chroma_id)?;
+    let level_id = level_id.to_lowercase();
+
+    let gun = loadout
+        .guns
+        .iter_mut()
+        .find(|g| g.id.eq_ignore_ascii_case(weapon_id))
+        .ok_or("Weapon not in loadout")?;
+
+    gun.skin_id = skin.skin_id.clone();
+    gun.skin_level_id = level_id;
+    gun.chroma_id = chroma_id;
+    Ok(())
+}
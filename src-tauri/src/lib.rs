@@ -1,10 +1,33 @@
 mod api;
+mod autolock;
+mod cache;
 mod commands;
+mod composition;
 mod constants;
+mod enrich;
+mod friends;
+mod gamemode;
+mod hotkeys;
+mod hud;
+mod inventory;
+mod loadout;
+mod logging;
+mod lookup;
+mod overlay;
+mod party;
+mod phase;
+mod scoreboard;
+mod server;
+mod stats;
+mod settings;
 mod state;
+mod store;
+mod teams;
+mod tray;
 
+use settings::WindowPosition;
 use state::AppState;
-use tauri::Manager;
+use tauri::{Manager, WindowEvent};
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
@@ -20,10 +43,65 @@ pub fn run() {
             commands::get_game_state,
             commands::set_auto_lock,
             commands::get_auto_lock,
+            commands::get_settings,
+            commands::update_settings,
+            commands::set_hotkey,
+            commands::pause_hotkeys,
+            commands::resume_hotkeys,
+            commands::get_hotkey_errors,
+            commands::set_overlay_settings,
+            commands::set_click_through,
+            commands::get_click_through,
+            commands::drag_hud,
+            commands::export_diagnostics,
+            commands::get_owned_agents,
+            commands::hover_agent,
+            commands::dodge_match,
+            commands::get_team_composition,
             commands::get_player_loadout,
+            commands::get_all_loadouts,
+            commands::get_storefront,
+            commands::get_wallet,
+            commands::get_owned_skins,
+            commands::get_my_loadout,
+            commands::set_my_loadout,
+            commands::equip_skin,
+            commands::get_party,
+            commands::set_party_queue,
+            commands::set_party_open,
+            commands::invite_to_party,
+            commands::kick_party_member,
+            commands::leave_party,
+            commands::start_queue,
+            commands::stop_queue,
+            commands::get_friends,
+            commands::get_server_history,
         ])
+        .on_window_event(|window, event| {
+            if let WindowEvent::Moved(pos) = event {
+                settings::remember_window_position(window.app_handle(), window.label(), WindowPosition { x: pos.x, y: pos.y });
+            }
+        })
         .setup(|app| {
-            // Window starts hidden, F2 toggles visibility
+            // Before anything else so problems loading settings end up in the log,
+            // the saved level is applied once they're loaded
+            if let Ok(log_dir) = app.path().app_log_dir() {
+                logging::init(log_dir, logging::DEFAULT_LOG_LEVEL);
+            }
+            if let Ok(cache_dir) = app.path().app_cache_dir() {
+                app.state::<AppState>().attach_disk_caches(cache_dir);
+            }
+            if let Ok(config_dir) = app.path().app_config_dir() {
+                app.state::<AppState>().settings.load(config_dir);
+            }
+            let _ = logging::set_level(&app.state::<AppState>().settings.get().log_level);
+            hud::create(app.handle())?;
+            settings::restore_window_position(app.handle(), "main");
+            settings::restore_window_position(app.handle(), hud::LABEL);
+            hotkeys::register_all(app.handle());
+            tray::init(app.handle())?;
+
+            // Window starts hidden, the toggle overlay hotkey shows it
             let window = app.get_webview_window("main").unwrap();
 
             #[cfg(debug_assertions)]
@@ -0,0 +1,241 @@
+use crate::api::content::ContentCatalog;
+use crate::api::types::{
+    Enrichment, LoadoutData, LoadoutItem, NamedItem, PlayerIdentity, PlayerSkinData, SpraySelection, WeaponSkin,
+};
+use crate::api::ValorantAPI;
+use crate::commands::get_cached_content;
+use crate::enrich;
+use crate::constants::{SOCKET_BUDDY, SOCKET_BUDDY_LEVEL, SOCKET_SKIN, SOCKET_SKIN_CHROMA, SOCKET_SKIN_LEVEL};
+use crate::state::AppState;
+use std::collections::HashMap;
+use tauri::{AppHandle, Manager};
+
+/// Which loadouts endpoint a match is read from - pregame only has my team
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LoadoutPhase {
+    Pregame,
+    Ingame,
+}
+
+/// Current match id and phase - coregame wins when both exist
+pub async fn current_match(api: &ValorantAPI) -> Option<(String, LoadoutPhase)> {
+    if let Some(id) = api.get_coregame_match_id().await {
+        return Some((id, LoadoutPhase::Ingame));
+    }
+    api.get_pregame_match_id()
+        .await
+        .map(|id| (id, LoadoutPhase::Pregame))
+}
+
+/// Prefetch the whole lobby's loadouts - only once per match id and phase
+/// A failed prefetch is retried on the next poll
+pub fn trigger_prefetch(app: &AppHandle, match_id: &str, phase: LoadoutPhase) {
+    let state = app.state::<AppState>();
+    let key = (match_id.to_string(), phase);
+    {
+        let mut last = state.loadouts_prefetched.write();
+        if last.as_ref() == Some(&key) {
+            return;
+        }
+        *last = Some(key.clone());
+    }
+
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let state = app.state::<AppState>();
+        if fetch_all(&state, &key.0, phase).await {
+            enrich::emit(&app, &key.0, Enrichment::Loadouts);
+            return;
+        }
+        // Marked up front so polls don't start duplicates, unmark so the next one retries
+        let mut last = state.loadouts_prefetched.write();
+        if last.as_ref() == Some(&key) {
+            *last = None;
+        }
+    });
+}
+
+/// Fetch and resolve every player's loadout into the cache
+/// Returns false if the loadouts endpoint failed
+pub async fn fetch_all(state: &AppState, match_id: &str, phase: LoadoutPhase) -> bool {
+    let api = &state.api;
+
+    // Card and title aren't part of the loadout, they come from the match identities
+    let (loadouts, identities): (Vec<LoadoutData>, HashMap<String, PlayerIdentity>) = match phase {
+        LoadoutPhase::Pregame => {
+            let Some(response) = api.get_pregame_loadouts(match_id).await else {
+                return false;
+            };
+            let identities = api
+                .get_pregame_match(match_id)
+                .await
+                .and_then(|m| m.ally_team)
+                .map(|t| {
+                    t.players
+                        .into_iter()
+                        .filter_map(|p| Some((p.subject, p.player_identity?)))
+                        .collect()
+                })
+                .unwrap_or_default();
+            (response.loadouts, identities)
+        }
+        LoadoutPhase::Ingame => {
+            let Some(response) = api.get_coregame_loadouts(match_id).await else {
+                return false;
+            };
+            let identities = api
+                .get_coregame_match(match_id)
+                .await
+                .map(|m| {
+                    m.players
+                        .into_iter()
+                        .filter_map(|p| Some((p.subject, p.player_identity?)))
+                        .collect()
+                })
+                .unwrap_or_default();
+            (response.loadouts.into_iter().map(|l| l.loadout).collect(), identities)
+        }
+    };
+
+    let catalog = get_cached_content(state).await;
+    let catalog = catalog.as_deref();
+
+    // New match or phase - drop the previous lobby
+    state.loadouts.set_scope(&scope(match_id, phase));
+    state.loadouts.insert_many(loadouts.iter().map(|data| {
+        (data.subject.clone(), to_skin_data(data, identities.get(&data.subject), catalog))
+    }));
+    true
+}
+
+/// Cache scope of a lobby's loadouts
+pub fn scope(match_id: &str, phase: LoadoutPhase) -> String {
+    format!("{}:{:?}", match_id, phase)
+}
+
+/// Single conversion for pregame and coregame loadouts
+pub fn to_skin_data(
+    data: &LoadoutData,
+    identity: Option<&PlayerIdentity>,
+    catalog: Option<&ContentCatalog>,
+) -> PlayerSkinData {
+    let sprays = data
+        .sprays
+        .as_ref()
+        .map(|s| resolve_sprays(&s.spray_selections, catalog))
+        .unwrap_or_default();
+    let (player_card, player_title) = resolve_identity(identity, catalog);
+
+    PlayerSkinData {
+        puuid: data.subject.clone(),
+        skins: resolve_weapons(&data.items, catalog),
+        sprays,
+        player_card,
+        player_title,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SocketType {
+    Skin,
+    SkinLevel,
+    SkinChroma,
+    Buddy,
+    BuddyLevel,
+    Unknown,
+}
+
+impl SocketType {
+    pub fn from_id(socket_id: &str) -> Self {
+        match socket_id.to_lowercase().as_str() {
+            SOCKET_SKIN => SocketType::Skin,
+            SOCKET_SKIN_LEVEL => SocketType::SkinLevel,
+            SOCKET_SKIN_CHROMA => SocketType::SkinChroma,
+            SOCKET_BUDDY => SocketType::Buddy,
+            SOCKET_BUDDY_LEVEL => SocketType::BuddyLevel,
+            _ => SocketType::Unknown,
+        }
+    }
+}
+
+/// Resolve weapon items (weapon id -> item) into named skins
+pub fn resolve_weapons(
+    items: &HashMap<String, LoadoutItem>,
+    catalog: Option<&ContentCatalog>,
+) -> Vec<WeaponSkin> {
+    let mut skins: Vec<WeaponSkin> = items
+        .iter()
+        .map(|(weapon_id, item)| resolve_weapon(weapon_id, item, catalog))
+        .collect();
+    skins.sort_by(|a, b| a.weapon_id.cmp(&b.weapon_id));
+    skins
+}
+
+fn resolve_weapon(weapon_id: &str, item: &LoadoutItem, catalog: Option<&ContentCatalog>) -> WeaponSkin {
+    let mut skin_id = None;
+    let mut level_id = None;
+    let mut chroma_id = None;
+    let mut buddy_id = None;
+
+    for (socket_id, socket) in item.sockets.iter().flatten() {
+        let id = Some(socket.item.id.clone());
+        match SocketType::from_id(socket_id) {
+            SocketType::Skin => skin_id = id,
+            SocketType::SkinLevel => level_id = id,
+            SocketType::SkinChroma => chroma_id = id,
+            // Buddy level is more specific, it resolves to the same name
+            SocketType::Buddy if buddy_id.is_none() => buddy_id = id,
+            SocketType::BuddyLevel => buddy_id = id,
+            _ => {}
+        }
+    }
+
+    let skin_id = skin_id.unwrap_or_else(|| item.id.clone());
+
+    WeaponSkin {
+        weapon_id: weapon_id.to_string(),
+        weapon_name: catalog.and_then(|c| c.weapon_name(weapon_id)),
+        skin_name: catalog.and_then(|c| c.skin_name(&skin_id)),
+        tier: catalog.and_then(|c| c.tier_name(&skin_id)),
+        chroma_name: catalog
+            .zip(chroma_id.as_deref())
+            .and_then(|(c, id)| c.skin(id).map(|s| s.name.clone())),
+        buddy_name: catalog
+            .zip(buddy_id.as_deref())
+            .and_then(|(c, id)| c.buddy_name(id)),
+        skin_id,
+        level_id,
+        chroma_id,
+        buddy_id,
+    }
+}
+
+pub fn resolve_sprays(selections: &[SpraySelection], catalog: Option<&ContentCatalog>) -> Vec<NamedItem> {
+    selections
+        .iter()
+        .map(|s| NamedItem {
+            id: s.spray_id.clone(),
+            name: catalog.and_then(|c| c.spray_name(&s.spray_id)),
+        })
+        .collect()
+}
+
+/// Player card and title from a match identity
+pub fn resolve_identity(
+    identity: Option<&PlayerIdentity>,
+    catalog: Option<&ContentCatalog>,
+) -> (Option<NamedItem>, Option<NamedItem>) {
+    let Some(identity) = identity else {
+        return (None, None);
+    };
+
+    let card = identity.player_card_id.as_ref().map(|id| NamedItem {
+        id: id.clone(),
+        name: catalog.and_then(|c| c.player_card_name(id)),
+    });
+    let title = identity.player_title_id.as_ref().map(|id| NamedItem {
+        id: id.clone(),
+        name: catalog.and_then(|c| c.player_title_name(id)),
+    });
+    (card, title)
+}
@@ -0,0 +1,163 @@
+use once_cell::sync::{Lazy, OnceCell};
+use regex::Regex;
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use tracing_appender::non_blocking::WorkerGuard;
+use tracing_appender::rolling::{RollingFileAppender, Rotation};
+use tracing_subscriber::fmt::{self, MakeWriter};
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::{reload, EnvFilter, Registry};
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipWriter};
+
+pub const LOG_LEVELS: [&str; 5] = ["error", "warn", "info", "debug", "trace"];
+pub const DEFAULT_LOG_LEVEL: &str = "info";
+// valorant-tracker.2026-01-31.log, one file per day
+const LOG_PREFIX: &str = "valorant-tracker";
+const LOG_SUFFIX: &str = "log";
+const MAX_LOG_FILES: usize = 7;
+
+// Level changes from the settings are applied through this handle
+static FILTER: OnceCell<reload::Handle<EnvFilter, Registry>> = OnceCell::new();
+// Dropping the guard stops the background writer, so it lives as long as the app
+static WRITER_GUARD: OnceCell<WorkerGuard> = OnceCell::new();
+
+static REDACTIONS: Lazy<Vec<(Regex, &'static str)>> = Lazy::new(|| {
+    vec![
+        // Access, entitlement and id tokens are JWTs
+        (Regex::new(r"eyJ[A-Za-z0-9_-]+\.[A-Za-z0-9_-]+\.[A-Za-z0-9_-]*").unwrap(), "[token]"),
+        // Authorization header values - the basic one carries the lockfile password
+        (Regex::new(r"(?i)\b(Basic|Bearer)\s+[A-Za-z0-9._~+/=-]+").unwrap(), "$1 [redacted]"),
+        (
+            Regex::new(r#"(?i)("?(?:access_token|id_token|token|password)"?\s*[:=]\s*"?)[^"&\s,}]+"#).unwrap(),
+            "${1}[redacted]",
+        ),
+        // Puuids (and match ids, which look the same) keep their first block so
+        // lines about the same player can still be matched up
+        (
+            Regex::new(r"(?i)\b([0-9a-f]{8})-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}\b").unwrap(),
+            "$1-…",
+        ),
+    ]
+});
+
+/// Strip tokens, passwords and puuids from text that leaves the process
+pub fn redact(text: &str) -> String {
+    REDACTIONS
+        .iter()
+        .fold(text.to_string(), |text, (pattern, replacement)| {
+            pattern.replace_all(&text, *replacement).into_owned()
+        })
+}
+
+/// Our own crate at the chosen level, dependencies only when something goes wrong
+fn filter_for(level: &str) -> EnvFilter {
+    EnvFilter::new(format!("warn,valorant_tracker_lib={}", level))
+}
+
+/// Install the subscriber - a daily rotated file in the log dir, plus stderr in debug builds
+pub fn init(log_dir: PathBuf, level: &str) {
+    let level = if LOG_LEVELS.contains(&level) { level } else { DEFAULT_LOG_LEVEL };
+    let (filter, handle) = reload::Layer::new(filter_for(level));
+
+    let appender = RollingFileAppender::builder()
+        .rotation(Rotation::DAILY)
+        .filename_prefix(LOG_PREFIX)
+        .filename_suffix(LOG_SUFFIX)
+        .max_log_files(MAX_LOG_FILES)
+        .build(&log_dir);
+    let file_layer = match appender {
+        Ok(appender) => {
+            let (writer, guard) = tracing_appender::non_blocking(appender);
+            let _ = WRITER_GUARD.set(guard);
+            Some(fmt::layer().with_ansi(false).with_writer(Redacted(writer)))
+        }
+        Err(e) => {
+            eprintln!("Failed to open log file in {}: {}", log_dir.display(), e);
+            None
+        }
+    };
+    let console_layer = cfg!(debug_assertions).then(|| fmt::layer().with_writer(Redacted(io::stderr)));
+
+    let installed = tracing_subscriber::registry()
+        .with(filter)
+        .with(file_layer)
+        .with(console_layer)
+        .try_init();
+    if installed.is_ok() {
+        let _ = FILTER.set(handle);
+    }
+    tracing::info!("Logging to {} at level {}", log_dir.display(), level);
+}
+
+/// Change the level without restarting
+pub fn set_level(level: &str) -> Result<(), String> {
+    if !LOG_LEVELS.contains(&level) {
+        return Err(format!("Unknown log level: {}", level));
+    }
+    if let Some(handle) = FILTER.get() {
+        handle.reload(filter_for(level)).map_err(|e| e.to_string())?;
+    }
+    tracing::info!("Log level set to {}", level);
+    Ok(())
+}
+
+/// Zip the log files and the given extra files into a bundle in the log dir
+/// Everything is redacted again in case it was written before redaction existed
+pub fn export_diagnostics(log_dir: &Path, extra: &[(&str, String)]) -> zip::result::ZipResult<PathBuf> {
+    fs::create_dir_all(log_dir)?;
+    let name = format!("diagnostics-{}.zip", chrono::Local::now().format("%Y%m%d-%H%M%S"));
+    let path = log_dir.join(name);
+
+    let mut zip = ZipWriter::new(File::create(&path)?);
+    // A week of text logs is small, storing keeps the zip crate free of compression backends
+    let options = SimpleFileOptions::default().compression_method(CompressionMethod::Stored);
+
+    let mut logs: Vec<PathBuf> = fs::read_dir(log_dir)?
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|p| p.file_name().is_some_and(|n| n.to_string_lossy().starts_with(LOG_PREFIX)))
+        .collect();
+    logs.sort();
+
+    for log in logs {
+        let Ok(bytes) = fs::read(&log) else { continue };
+        let name = log.file_name().unwrap_or_default().to_string_lossy().into_owned();
+        zip.start_file(format!("logs/{}", name), options)?;
+        zip.write_all(redact(&String::from_utf8_lossy(&bytes)).as_bytes())?;
+    }
+    for (name, content) in extra {
+        zip.start_file(*name, options)?;
+        zip.write_all(redact(content).as_bytes())?;
+    }
+
+    zip.finish()?;
+    Ok(path)
+}
+
+/// Writer wrapper that redacts every formatted event before it's written
+struct Redacted<M>(M);
+
+impl<'a, M: MakeWriter<'a>> MakeWriter<'a> for Redacted<M> {
+    type Writer = RedactedWriter<M::Writer>;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        RedactedWriter(self.0.make_writer())
+    }
+}
+
+struct RedactedWriter<W>(W);
+
+impl<W: Write> Write for RedactedWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        // The fmt layer writes one whole event per call
+        self.0.write_all(redact(&String::from_utf8_lossy(buf)).as_bytes())?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.0.flush()
+    }
+}
@@ -0,0 +1,215 @@
+use futures_util::stream::{self, StreamExt};
+use std::collections::HashMap;
+use std::future::Future;
+use std::hash::Hash;
+
+// Requests in flight per batch - the scheduler still throttles per host
+pub const MAX_CONCURRENT: usize = 10;
+
+/// Run one lookup per key with a bounded number in flight
+/// Keys whose lookup returned None are left out
+pub async fn fetch_each<K, V, F, Fut>(keys: impl IntoIterator<Item = K>, lookup: F) -> HashMap<K, V>
+where
+    K: Clone + Eq + Hash,
+    F: Fn(K) -> Fut,
+    Fut: Future<Output = Option<V>>,
+{
+    stream::iter(keys)
+        .map(|key| {
+            let pending = lookup(key.clone());
+            async move { pending.await.map(|value| (key, value)) }
+        })
+        .buffer_unordered(MAX_CONCURRENT)
+        .filter_map(|result| async move { result })
+        .collect()
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::commands::{get_cached_names, get_cached_ranks};
+    use crate::state::AppState;
+    use parking_lot::Mutex;
+    use serde_json::{json, Value};
+    use std::sync::Arc;
+    use std::time::{Duration, Instant};
+    use wiremock::matchers::{method, path, path_regex};
+    use wiremock::{Mock, MockServer, Request, ResponseTemplate};
+
+    // Every mocked endpoint answers this slowly, so sequential lookups would be obvious
+    const LATENCY: Duration = Duration::from_millis(500);
+    const CURRENT_ACT: &str = "current-act";
+
+    fn lobby(size: usize) -> Vec<String> {
+        (0..size).map(|i| format!("player-{}", i)).collect()
+    }
+
+    async fn mock_state() -> (MockServer, AppState) {
+        let server = MockServer::start().await;
+        let state = AppState::new();
+        state.api.mock_remote(&server.uri());
+        (server, state)
+    }
+
+    /// When each request reached a mocked endpoint
+    #[derive(Clone, Default)]
+    struct Arrivals(Arc<Mutex<Vec<Instant>>>);
+
+    impl Arrivals {
+        fn respond(
+            &self,
+            body: impl Fn(&Request) -> Value + Send + Sync + 'static,
+        ) -> impl Fn(&Request) -> ResponseTemplate + Send + Sync + 'static {
+            let arrivals = self.clone();
+            move |request: &Request| {
+                arrivals.0.lock().push(Instant::now());
+                ResponseTemplate::new(200).set_body_json(body(request)).set_delay(LATENCY)
+            }
+        }
+
+        fn count(&self) -> usize {
+            self.0.lock().len()
+        }
+
+        /// Most requests being answered at the same time
+        fn peak(&self) -> usize {
+            let arrivals = self.0.lock();
+            arrivals
+                .iter()
+                .map(|start| arrivals.iter().filter(|t| **t >= *start && **t < *start + LATENCY).count())
+                .max()
+                .unwrap_or(0)
+        }
+    }
+
+    async fn mount_seasons(server: &MockServer) {
+        let seasons = json!({ "Seasons": [
+            { "ID": "previous-act", "Type": "act", "IsActive": false },
+            { "ID": "current-episode", "Type": "episode", "IsActive": true },
+            { "ID": CURRENT_ACT, "Type": "act", "IsActive": true },
+        ]});
+        Mock::given(method("GET"))
+            .and(path("/content-service/v3/content"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(seasons))
+            .expect(1)
+            .mount(server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn ranks_for_a_lobby_take_one_round_trip() {
+        let (server, state) = mock_state().await;
+        let puuids = lobby(10);
+        mount_seasons(&server).await;
+
+        // The latest update is from the previous act and must not win over this act's rank
+        let mmr = Arrivals::default();
+        Mock::given(method("GET"))
+            .and(path_regex("^/mmr/v1/players/"))
+            .respond_with(mmr.respond(|_| {
+                json!({
+                    "QueueSkills": { "competitive": { "SeasonalInfoBySeasonID": {
+                        CURRENT_ACT: { "CompetitiveTier": 15, "RankedRating": 40 },
+                    }}},
+                    "LatestCompetitiveUpdate": {
+                        "SeasonID": "previous-act", "TierAfterUpdate": 21, "RankedRatingAfterUpdate": 80,
+                    },
+                })
+            }))
+            .expect(10)
+            .mount(&server)
+            .await;
+
+        let started = Instant::now();
+        let ranks = get_cached_ranks(&state, "match", &puuids).await;
+        let elapsed = started.elapsed();
+
+        assert_eq!(ranks.len(), 10);
+        assert!(ranks.values().all(|r| r.tier == 15 && r.rr == 40));
+        assert_eq!(mmr.count(), 10, "one MMR request per player");
+        assert_eq!(mmr.peak(), 10, "every MMR request in flight at once");
+        // Sequential would be 10 x LATENCY
+        assert!(elapsed < LATENCY * 2, "took {:?}", elapsed);
+
+        // Same match - served from the cache
+        get_cached_ranks(&state, "match", &puuids).await;
+        assert_eq!(mmr.count(), 10);
+    }
+
+    #[tokio::test]
+    async fn unranked_this_act_ignores_older_acts() {
+        let (server, state) = mock_state().await;
+        mount_seasons(&server).await;
+
+        Mock::given(method("GET"))
+            .and(path_regex("^/mmr/v1/players/"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "QueueSkills": { "competitive": { "SeasonalInfoBySeasonID": {
+                    "previous-act": { "CompetitiveTier": 18, "RankedRating": 12 },
+                }}},
+                "LatestCompetitiveUpdate": {
+                    "SeasonID": "previous-act", "TierAfterUpdate": 18, "RankedRatingAfterUpdate": 12,
+                },
+            })))
+            .mount(&server)
+            .await;
+
+        assert_eq!(state.api.get_player_mmr("player-0").await, Some((0, 0)));
+    }
+
+    #[tokio::test]
+    async fn names_for_a_lobby_are_one_request() {
+        let (server, state) = mock_state().await;
+        let puuids = lobby(10);
+
+        let names = Arrivals::default();
+        Mock::given(method("PUT"))
+            .and(path("/name-service/v2/players"))
+            .respond_with(names.respond(|request| {
+                let puuids: Vec<String> = request.body_json().unwrap_or_default();
+                puuids
+                    .iter()
+                    .map(|p| json!({ "Subject": p, "GameName": p, "TagLine": "TAG" }))
+                    .collect()
+            }))
+            .mount(&server)
+            .await;
+
+        let resolved = get_cached_names(&state, &puuids).await;
+        assert_eq!(resolved.len(), 10);
+        assert_eq!(resolved["player-3"], "player-3#TAG");
+        assert_eq!(names.count(), 1, "names are looked up in a single batch");
+
+        get_cached_names(&state, &puuids).await;
+        assert_eq!(names.count(), 1);
+    }
+
+    #[tokio::test]
+    async fn match_details_are_fetched_together_in_order() {
+        let (server, state) = mock_state().await;
+        let match_ids: Vec<String> = (0..4).map(|i| format!("match-{}", i)).collect();
+
+        let details = Arrivals::default();
+        Mock::given(method("GET"))
+            .and(path_regex("^/match-details/v1/matches/"))
+            .respond_with(details.respond(|request| {
+                let id = request.url.path_segments().and_then(|mut s| s.next_back()).unwrap_or_default();
+                json!({ "matchInfo": { "matchId": id }, "players": [] })
+            }))
+            .mount(&server)
+            .await;
+
+        let started = Instant::now();
+        let fetched = state.api.get_match_details_many(&match_ids).await;
+        let elapsed = started.elapsed();
+
+        let order: Vec<String> = fetched
+            .iter()
+            .filter_map(|d| d.match_info.as_ref()?.match_id.clone())
+            .collect();
+        assert_eq!(order, match_ids);
+        assert_eq!(details.count(), 4);
+        assert_eq!(details.peak(), 4);
+        assert!(elapsed < LATENCY * 2, "took {:?}", elapsed);
+    }
+}
@@ -0,0 +1,73 @@
+use crate::hud;
+use crate::settings::{self, OverlaySettings};
+use crate::state::AppState;
+use crate::tray;
+use tauri::{AppHandle, Emitter, Manager};
+
+// Below this the overlay is hard to find again
+const MIN_OPACITY: f64 = 0.2;
+
+/// Apply the user's show/hide rules to a phase transition
+pub fn on_phase_change(app: &AppHandle, from: &str, to: &str) {
+    let rules = app.state::<AppState>().settings.get().overlay;
+
+    let visible = match (from, to) {
+        (_, "pregame") if rules.show_on_pregame => true,
+        (_, "ingame") if rules.hide_on_match_start => false,
+        // Cancelling a queue stays in the lobby and isn't a reason to hide
+        ("pregame" | "ingame", "idle") if rules.hide_on_idle => false,
+        _ => return,
+    };
+    set_visible(app, visible);
+}
+
+/// Show or hide without taking focus from the game
+pub fn set_visible(app: &AppHandle, visible: bool) {
+    let Some(window) = app.get_webview_window("main") else { return };
+    let _ = if visible { window.show() } else { window.hide() };
+}
+
+/// Hotkey and tray toggle - focuses the window when showing it
+pub fn toggle_visible(app: &AppHandle) {
+    let Some(window) = app.get_webview_window("main") else { return };
+    if window.is_visible().unwrap_or(false) {
+        let _ = window.hide();
+    } else {
+        let _ = window.show();
+        let _ = window.set_focus();
+    }
+}
+
+/// Let mouse input pass through the overlay windows to the game
+pub fn set_click_through(app: &AppHandle, enabled: bool) {
+    let state = app.state::<AppState>();
+    *state.click_through.write() = enabled;
+
+    for label in ["main", hud::LABEL] {
+        let Some(window) = app.get_webview_window(label) else { continue };
+        if let Err(e) = window.set_ignore_cursor_events(enabled) {
+            tracing::warn!("Failed to set click-through on {}: {}", label, e);
+        }
+    }
+    let _ = app.emit("click-through-changed", enabled);
+    tray::refresh(app);
+}
+
+pub fn toggle_click_through(app: &AppHandle) {
+    let enabled = !*app.state::<AppState>().click_through.read();
+    set_click_through(app, enabled);
+}
+
+/// Save new overlay rules and opacity - opacity is applied by the frontend
+pub fn update(app: &AppHandle, mut overlay: OverlaySettings) -> settings::Settings {
+    clamp(&mut overlay);
+    let updated = app.state::<AppState>().settings.modify(|s| s.overlay = overlay);
+    settings::changed(app, &updated);
+    hud::sync(app);
+    updated
+}
+
+/// Keep the overlay settings in the range the windows can use
+pub fn clamp(overlay: &mut OverlaySettings) {
+    overlay.opacity = overlay.opacity.clamp(MIN_OPACITY, 1.0);
+}
@@ -0,0 +1,56 @@
+use crate::api::types::{PartyInfo, PartyMemberInfo, PartyResponse};
+use crate::constants::QUEUE_NAMES;
+use std::collections::HashMap;
+
+/// Party is searching for a match, or has just found one
+pub fn is_queuing(raw: &PartyResponse) -> bool {
+    matches!(raw.state.as_deref(), Some("MATCHMAKING") | Some("MATCHMADE_GAME_STARTING"))
+}
+
+/// Seconds since the party entered the queue - Riot sends UTC as "2024.05.29-18.22.07"
+pub fn queue_elapsed_secs(queue_entry_time: &str) -> Option<u64> {
+    let entered = chrono::NaiveDateTime::parse_from_str(queue_entry_time, "%Y.%m.%d-%H.%M.%S").ok()?;
+    let elapsed = chrono::Utc::now().naive_utc().signed_duration_since(entered);
+    u64::try_from(elapsed.num_seconds()).ok()
+}
+
+/// Build the frontend party view - names come from the name service
+pub fn build_party_info(
+    party_id: &str,
+    raw: PartyResponse,
+    names: &HashMap<String, String>,
+    my_puuid: &str,
+) -> PartyInfo {
+    let queue_id = raw.matchmaking_data.and_then(|m| m.queue_id).filter(|q| !q.is_empty());
+    let queue_name = queue_id
+        .as_deref()
+        .map(|q| QUEUE_NAMES.get(q).map(|s| s.to_string()).unwrap_or_else(|| q.to_string()));
+
+    let members = raw
+        .members
+        .into_iter()
+        .filter_map(|m| {
+            let puuid = m.subject?;
+            Some(PartyMemberInfo {
+                name: names.get(&puuid).cloned().unwrap_or_else(|| "Unknown".into()),
+                is_me: puuid == my_puuid,
+                is_owner: m.is_owner.unwrap_or(false),
+                is_ready: m.is_ready.unwrap_or(false),
+                rank_tier: m.competitive_tier.unwrap_or(0),
+                level: m.player_identity.as_ref().map(|i| i.account_level).unwrap_or(0),
+                player_card_id: m.player_identity.and_then(|i| i.player_card_id),
+                puuid,
+            })
+        })
+        .collect();
+
+    PartyInfo {
+        party_id: raw.id.unwrap_or_else(|| party_id.to_string()),
+        state: raw.state.unwrap_or_else(|| "DEFAULT".into()),
+        queue_id,
+        queue_name,
+        is_open: raw.accessibility.as_deref() == Some("OPEN"),
+        eligible_queues: raw.eligible_queues.unwrap_or_default(),
+        members,
+    }
+}
@@ -0,0 +1,37 @@
+use crate::api::types::{GameState, PhaseChange};
+use crate::hud;
+use crate::overlay;
+use crate::state::AppState;
+use crate::tray;
+use tauri::{AppHandle, Emitter, Manager, UserAttentionType};
+
+/// Track GameState.state transitions and fire the matching events
+pub fn update(app: &AppHandle, game_state: &GameState) {
+    let state = app.state::<AppState>();
+    let previous = std::mem::replace(&mut *state.phase.write(), game_state.state.clone());
+    if previous == game_state.state {
+        return;
+    }
+    tracing::info!("Phase {} -> {}", previous, game_state.state);
+
+    let _ = app.emit(
+        "phase-changed",
+        PhaseChange {
+            from: previous.clone(),
+            to: game_state.state.clone(),
+            match_id: game_state.match_id.clone(),
+        },
+    );
+    overlay::on_phase_change(app, &previous, &game_state.state);
+    hud::sync(app);
+    tray::refresh(app);
+
+    // Short queues can finish between two polls, so idle -> pregame counts too
+    // Starting the app during agent select comes from "unknown" and doesn't
+    if game_state.state == "pregame" && (previous == "queuing" || previous == "idle") {
+        let _ = app.emit("match-found", &game_state.match_id);
+        if let Some(window) = app.get_webview_window("main") {
+            let _ = window.request_user_attention(Some(UserAttentionType::Critical));
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use crate::api::types::PresenceDetails;
+
+/// Live score from my own presence - (ally, enemy, current round)
+pub fn live_score(presence: &PresenceDetails) -> Option<(u32, u32, u32)> {
+    let ally = presence.score_ally?;
+    let enemy = presence.score_enemy?;
+    Some((ally, enemy, ally + enemy + 1))
+}
+
+/// Rounds per half - None for modes without attack/defense sides
+pub fn half_length(queue_id: Option<&str>) -> Option<u32> {
+    match queue_id.unwrap_or_default() {
+        "deathmatch" | "hurm" | "ggteam" => None,
+        "swiftplay" => Some(4),
+        "spikerush" => Some(3),
+        _ => Some(12),
+    }
+}
+
+/// Red attacks first; sides swap at half time and every overtime round
+pub fn current_side(team_id: &str, round: u32, half_length: u32) -> &'static str {
+    let played = round.saturating_sub(1);
+    let swapped = if played < half_length * 2 {
+        played >= half_length
+    } else {
+        (played - half_length * 2) % 2 == 1
+    };
+
+    let attacking = (team_id == "Red") != swapped;
+    if attacking { "SALDIRAN" } else { "SAVUNAN" }
+}
@@ -0,0 +1,128 @@
+use crate::api::types::{ServerInfo, ServerStats};
+use crate::state::AppState;
+
+// Cities whose pod names are run together
+const CITY_NAMES: &[(&str, &str)] = &[
+    ("losangeles", "Los Angeles"),
+    ("hongkong", "Hong Kong"),
+    ("saopaulo", "São Paulo"),
+    ("mexicocity", "Mexico City"),
+    ("telaviv", "Tel Aviv"),
+    ("newyork", "New York"),
+];
+
+/// "aresriot.aws-rclusterprod-euc1-1.eu-gp-frankfurt-1" -> Frankfurt 1 in aws-rclusterprod-euc1-1
+pub fn parse_pod_id(pod_id: &str) -> Option<ServerInfo> {
+    let mut parts = pod_id.split('.');
+    let _owner = parts.next()?;
+    let data_center = parts.next()?;
+    let pod = parts.next()?;
+
+    // "eu-gp-frankfurt-1" -> region, "gp", city, optional index
+    let mut pod_parts = pod.split('-');
+    let region = pod_parts.next()?;
+    let rest: Vec<&str> = pod_parts.skip_while(|p| *p == "gp").collect();
+    let (city, index) = match rest.split_last() {
+        Some((last, city)) if last.chars().all(|c| c.is_ascii_digit()) => (city.join(""), Some(*last)),
+        _ => (rest.join(""), None),
+    };
+    if city.is_empty() {
+        return None;
+    }
+
+    let city = city_name(&city);
+    let location = match index {
+        Some(index) => format!("{} {}", city, index),
+        None => city,
+    };
+
+    Some(ServerInfo {
+        pod_id: pod_id.to_string(),
+        location,
+        region: region.to_string(),
+        data_center: data_center.to_string(),
+        provider: data_center.split('-').next().unwrap_or_default().to_string(),
+    })
+}
+
+fn city_name(city: &str) -> String {
+    if let Some((_, name)) = CITY_NAMES.iter().find(|(id, _)| *id == city) {
+        return name.to_string();
+    }
+    let mut chars = city.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// Count a match towards its server - each match id is only counted once
+pub fn record_match(state: &AppState, match_id: &str, server: &ServerInfo) {
+    let mut history = state.server_history.write();
+    if !history.counted_matches.insert(match_id.to_string()) {
+        return;
+    }
+
+    let now = chrono::Utc::now().to_rfc3339();
+    history
+        .servers
+        .entry(server.location.clone())
+        .and_modify(|s| {
+            s.matches += 1;
+            s.data_center = server.data_center.clone();
+            s.last_seen = now.clone();
+        })
+        .or_insert_with(|| ServerStats {
+            location: server.location.clone(),
+            region: server.region.clone(),
+            data_center: server.data_center.clone(),
+            matches: 1,
+            last_seen: now,
+        });
+}
+
+/// Servers seen this session, most frequent first
+pub fn session_stats(state: &AppState) -> Vec<ServerStats> {
+    let mut stats: Vec<ServerStats> = state.server_history.read().servers.values().cloned().collect();
+    stats.sort_by(|a, b| b.matches.cmp(&a.matches).then_with(|| b.last_seen.cmp(&a.last_seen)));
+    stats
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_an_aws_pod() {
+        let server = parse_pod_id("aresriot.aws-rclusterprod-euc1-1.eu-gp-frankfurt-1").unwrap();
+
+        assert_eq!(server.pod_id, "aresriot.aws-rclusterprod-euc1-1.eu-gp-frankfurt-1");
+        assert_eq!(server.location, "Frankfurt 1");
+        assert_eq!(server.region, "eu");
+        assert_eq!(server.data_center, "aws-rclusterprod-euc1-1");
+        assert_eq!(server.provider, "aws");
+    }
+
+    #[test]
+    fn keeps_unknown_data_centers_and_cities_as_reported() {
+        let server = parse_pod_id("aresriot.mtl-riot-ist1-2.tr-gp-istanbul").unwrap();
+
+        assert_eq!(server.location, "Istanbul");
+        assert_eq!(server.region, "tr");
+        assert_eq!(server.data_center, "mtl-riot-ist1-2");
+        assert_eq!(server.provider, "mtl");
+    }
+
+    #[test]
+    fn names_run_together_cities() {
+        let server = parse_pod_id("aresriot.aws-rclusterprod-usw2-1.na-gp-losangeles-1").unwrap();
+        assert_eq!(server.location, "Los Angeles 1");
+    }
+
+    #[test]
+    fn malformed_pods_are_none() {
+        for pod_id in ["", "aresriot", "aresriot.aws-rclusterprod-euc1-1", "aresriot.aws.eu", "aresriot.aws.eu-gp-1"] {
+            assert!(parse_pod_id(pod_id).is_none(), "{}", pod_id);
+        }
+    }
+}
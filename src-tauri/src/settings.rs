@@ -0,0 +1,289 @@
+use crate::cache;
+use crate::hud;
+use crate::logging;
+use crate::state::AppState;
+use crate::tray;
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, Manager};
+
+pub const SETTINGS_VERSION: u32 = 2;
+const SETTINGS_FILE: &str = "settings.json";
+// An unreadable settings file is moved here instead of being overwritten
+const BACKUP_FILE: &str = "settings.json.bak";
+// Window moves arrive for every pixel while dragging
+const SAVE_DELAY: Duration = Duration::from_millis(500);
+
+/// User settings, persisted as JSON in the app config dir
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    pub version: u32,
+    pub auto_lock_agent: Option<String>,
+    // Tray toggle - keeps the agent so it can be switched back on
+    pub auto_lock_enabled: bool,
+    pub hotkeys: Hotkeys,
+    pub overlay: OverlaySettings,
+    pub window_position: Option<WindowPosition>,
+    pub hud_position: Option<WindowPosition>,
+    // One of logging::LOG_LEVELS
+    pub log_level: String,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            version: SETTINGS_VERSION,
+            auto_lock_agent: None,
+            auto_lock_enabled: true,
+            hotkeys: Hotkeys::default(),
+            overlay: OverlaySettings::default(),
+            window_position: None,
+            hud_position: None,
+            log_level: logging::DEFAULT_LOG_LEVEL.into(),
+        }
+    }
+}
+
+impl Settings {
+    /// Agent to lock, if auto-lock is switched on
+    pub fn auto_lock(&self) -> Option<&str> {
+        self.auto_lock_agent.as_deref().filter(|_| self.auto_lock_enabled)
+    }
+
+    /// Saved position slot for a window label - unknown windows aren't remembered
+    fn position_mut(&mut self, label: &str) -> Option<&mut Option<WindowPosition>> {
+        match label {
+            "main" => Some(&mut self.window_position),
+            hud::LABEL => Some(&mut self.hud_position),
+            _ => None,
+        }
+    }
+}
+
+/// Global hotkey per action - None leaves the action unbound
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Hotkeys {
+    pub toggle_overlay: Option<String>,
+    pub toggle_click_through: Option<String>,
+    pub refresh_state: Option<String>,
+    pub cycle_panels: Option<String>,
+}
+
+impl Default for Hotkeys {
+    fn default() -> Self {
+        Self {
+            toggle_overlay: Some("F2".into()),
+            toggle_click_through: Some("Ctrl+F2".into()),
+            refresh_state: None,
+            cycle_panels: None,
+        }
+    }
+}
+
+/// When the overlay shows and hides itself, and how see-through it is
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct OverlaySettings {
+    pub show_on_pregame: bool,
+    pub hide_on_match_start: bool,
+    // Back in the lobby after agent select or a match
+    pub hide_on_idle: bool,
+    // Compact enemy strip while a match is running
+    pub show_hud: bool,
+    pub opacity: f64,
+}
+
+impl Default for OverlaySettings {
+    fn default() -> Self {
+        Self {
+            show_on_pregame: true,
+            hide_on_match_start: false,
+            hide_on_idle: false,
+            show_hud: true,
+            opacity: 1.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WindowPosition {
+    pub x: i32,
+    pub y: i32,
+}
+
+/// Bring an older settings file up to SETTINGS_VERSION, one version at a time
+fn migrate(mut value: Value) -> Value {
+    let mut version = value.get("version").and_then(Value::as_u64).unwrap_or(0);
+
+    while version < SETTINGS_VERSION as u64 {
+        let Some(obj) = value.as_object_mut() else { break };
+
+        // v1 had a single overlay hotkey
+        if version == 1 {
+            if let Some(hotkey) = obj.remove("hotkey") {
+                obj.entry("hotkeys").or_insert(serde_json::json!({ "toggle_overlay": hotkey }));
+            }
+        }
+        version += 1;
+    }
+
+    if let Some(obj) = value.as_object_mut() {
+        obj.insert("version".into(), SETTINGS_VERSION.into());
+    }
+    value
+}
+
+/// Merge a patch into settings JSON - objects are merged key by key so a nested
+/// patch like `{"overlay": {"opacity": 0.5}}` keeps the other overlay fields
+fn merge_json(target: &mut Value, patch: Value) {
+    match (target, patch) {
+        (Value::Object(target), Value::Object(patch)) => {
+            for (key, value) in patch {
+                merge_json(target.entry(key).or_insert(Value::Null), value);
+            }
+        }
+        (target, patch) => *target = patch,
+    }
+}
+
+fn parse(bytes: &[u8]) -> Result<Settings, String> {
+    let value = serde_json::from_slice::<Value>(bytes).map_err(|e| e.to_string())?;
+    serde_json::from_value(migrate(value)).map_err(|e| e.to_string())
+}
+
+/// Single source of truth for settings - commands and subsystems read from here
+#[derive(Default)]
+pub struct SettingsStore {
+    current: RwLock<Settings>,
+    path: RwLock<Option<PathBuf>>,
+    // Bumped on every delayed save so only the last one writes
+    pending_save: AtomicU64,
+}
+
+impl SettingsStore {
+    pub fn get(&self) -> Settings {
+        self.current.read().clone()
+    }
+
+    /// Load settings from the config dir, falling back to defaults
+    /// A file that can't be read is kept as settings.json.bak rather than overwritten
+    pub fn load(&self, config_dir: PathBuf) {
+        let path = config_dir.join(SETTINGS_FILE);
+        let loaded = match std::fs::read(&path) {
+            Ok(bytes) => parse(&bytes).map(Some),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e.to_string()),
+        };
+        let loaded = loaded.unwrap_or_else(|e| {
+            tracing::warn!("Failed to load {}, using defaults: {}", path.display(), e);
+            if let Err(e) = std::fs::rename(&path, config_dir.join(BACKUP_FILE)) {
+                tracing::warn!("Failed to back up settings: {}", e);
+            }
+            None
+        });
+
+        let migrate_file = loaded.is_some();
+        *self.current.write() = loaded.unwrap_or_default();
+        *self.path.write() = Some(path);
+
+        // Write back so the file is on the current version
+        if migrate_file {
+            self.save();
+        }
+    }
+
+    pub fn save(&self) {
+        let Some(path) = self.path.read().clone() else { return };
+        let settings = self.get();
+
+        match serde_json::to_vec_pretty(&settings) {
+            Ok(bytes) => {
+                // A truncated file would be moved to the backup and reset to defaults on the next start
+                if let Err(e) = cache::write_atomic(&path, &bytes) {
+                    tracing::warn!("Failed to save settings: {}", e);
+                }
+            }
+            Err(e) => tracing::warn!("Failed to serialize settings: {}", e),
+        }
+    }
+
+    /// The settings with a partial JSON object merged in, nothing is stored yet
+    /// Unknown keys are ignored, invalid values reject the whole patch
+    pub fn merged(&self, patch: Value) -> Result<Settings, String> {
+        let Value::Object(patch) = patch else {
+            return Err("Settings patch must be an object".into());
+        };
+
+        let mut value = serde_json::to_value(self.get()).map_err(|e| e.to_string())?;
+        if let Some(obj) = value.as_object_mut() {
+            for (key, v) in patch {
+                if key != "version" {
+                    merge_json(obj.entry(key).or_insert(Value::Null), v);
+                }
+            }
+        }
+
+        serde_json::from_value(value).map_err(|e| e.to_string())
+    }
+
+    pub fn set(&self, settings: Settings) {
+        *self.current.write() = settings;
+    }
+
+    pub fn modify(&self, f: impl FnOnce(&mut Settings)) -> Settings {
+        let mut current = self.current.write();
+        f(&mut current);
+        current.clone()
+    }
+}
+
+/// Save and tell every window and the tray about the new settings
+pub fn changed(app: &AppHandle, settings: &Settings) {
+    app.state::<AppState>().settings.save();
+    let _ = app.emit("settings-changed", settings);
+    tray::refresh(app);
+}
+
+/// Remember where a window was moved to, saving once the drag has settled
+pub fn remember_window_position(app: &AppHandle, label: &str, position: WindowPosition) {
+    let state = app.state::<AppState>();
+    let settings = &state.settings;
+
+    let mut moved = false;
+    settings.modify(|s| {
+        if let Some(slot) = s.position_mut(label) {
+            moved = *slot != Some(position);
+            *slot = Some(position);
+        }
+    });
+    if !moved {
+        return;
+    }
+
+    let generation = settings.pending_save.fetch_add(1, Ordering::SeqCst) + 1;
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        tokio::time::sleep(SAVE_DELAY).await;
+        let state = app.state::<AppState>();
+        if state.settings.pending_save.load(Ordering::SeqCst) == generation {
+            state.settings.save();
+        }
+    });
+}
+
+/// Put a window back where it was last time
+pub fn restore_window_position(app: &AppHandle, label: &str) {
+    let mut settings = app.state::<AppState>().settings.get();
+    let Some(position) = settings.position_mut(label).and_then(|slot| *slot) else {
+        return;
+    };
+    if let Some(window) = app.get_webview_window(label) {
+        let _ = window.set_position(tauri::PhysicalPosition::new(position.x, position.y));
+    }
+}
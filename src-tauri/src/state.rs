@@ -1,33 +1,88 @@
+use crate::api::content::ContentCatalog;
 use crate::api::ValorantAPI;
-use crate::api::types::PlayerSkinData;
+use crate::loadout::LoadoutPhase;
+use crate::api::types::{PlayerSkinData, PlayerStats, RankInfo, ServerStats};
+use crate::cache::{self, Cache};
+use crate::enrich::EnrichmentRound;
+use crate::hotkeys::HotkeyError;
+use crate::settings::SettingsStore;
 use parking_lot::RwLock;
 use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
 use std::sync::Arc;
 
 pub struct AppState {
     pub api: Arc<ValorantAPI>,
-    pub auto_lock_agent: RwLock<Option<String>>,
-    // Cache for party detection - persists across pregame->ingame transition
-    pub cached_parties: RwLock<HashMap<String, String>>,
+    // Last GameState.state seen by get_game_state - "unknown" until the first poll
+    pub phase: RwLock<String>,
+    pub settings: SettingsStore,
+    // Last pregame match the auto-lock task was started for
+    pub auto_lock_match_id: RwLock<Option<String>>,
+    // Owned agent ids - fetched once per session
+    pub owned_agents: RwLock<Option<HashSet<String>>>,
+    // puuid -> "Name#TAG"
+    pub names: Cache<String>,
+    // puuid -> tier and RR, scoped to the match id
+    pub ranks: Cache<RankInfo>,
+    // puuid -> recent match totals, scoped to the match id
+    pub stats: Cache<PlayerStats>,
+    // Party detection - persists across pregame->ingame transition
+    pub parties: Cache<String>,
     // Track if we're in an active game session (pregame or ingame)
     pub in_game_session: RwLock<bool>,
-    // Cache for players whose match history has been fetched this game session
-    pub fetched_history_players: RwLock<HashSet<String>>,
-    // Cache for player loadouts - puuid -> skins
-    pub cached_loadouts: RwLock<HashMap<String, PlayerSkinData>>,
-    pub loadouts_match_id: RwLock<Option<String>>,
+    // Main window lets clicks through to the game
+    pub click_through: RwLock<bool>,
+    // Bindings that failed to register last time
+    pub hotkey_errors: RwLock<Vec<HotkeyError>>,
+    // Players whose match history has been fetched this game session
+    pub history_fetched: Cache<()>,
+    // Weapon/skin/buddy names from valorant-api.com - scoped to the client version
+    pub content: Cache<Arc<ContentCatalog>>,
+    // puuid -> skins, scoped to the match id and phase
+    pub loadouts: Cache<PlayerSkinData>,
+    // Last match/phase a lobby-wide loadout prefetch was started for
+    pub loadouts_prefetched: RwLock<Option<(String, LoadoutPhase)>>,
+    // Last round of roster enrichment tasks
+    pub enrichment_started: RwLock<Option<EnrichmentRound>>,
+    // Servers played on since the app started
+    pub server_history: RwLock<ServerHistory>,
+}
+
+#[derive(Default)]
+pub struct ServerHistory {
+    // Location -> stats
+    pub servers: HashMap<String, ServerStats>,
+    pub counted_matches: HashSet<String>,
 }
 
 impl AppState {
     pub fn new() -> Self {
         Self {
             api: Arc::new(ValorantAPI::new()),
-            auto_lock_agent: RwLock::new(None),
-            cached_parties: RwLock::new(HashMap::new()),
+            phase: RwLock::new("unknown".into()),
+            settings: SettingsStore::default(),
+            auto_lock_match_id: RwLock::new(None),
+            owned_agents: RwLock::new(None),
+            names: Cache::new(cache::NAMES_TTL),
+            ranks: Cache::new(cache::RANKS_TTL),
+            stats: Cache::new(cache::STATS_TTL),
+            parties: Cache::new(cache::SESSION_TTL),
             in_game_session: RwLock::new(false),
-            fetched_history_players: RwLock::new(HashSet::new()),
-            cached_loadouts: RwLock::new(HashMap::new()),
-            loadouts_match_id: RwLock::new(None),
+            click_through: RwLock::new(false),
+            hotkey_errors: RwLock::new(Vec::new()),
+            history_fetched: Cache::new(cache::SESSION_TTL),
+            content: Cache::new(cache::CONTENT_TTL),
+            loadouts: Cache::new(cache::LOADOUTS_TTL),
+            loadouts_prefetched: RwLock::new(None),
+            enrichment_started: RwLock::new(None),
+            server_history: RwLock::new(ServerHistory::default()),
         }
     }
 }
+
+impl AppState {
+    /// Caches that survive restarts live in the app cache dir
+    pub fn attach_disk_caches(&self, cache_dir: PathBuf) {
+        self.api.match_details.attach_disk(cache_dir.join("match-details.json"));
+    }
+}
@@ -0,0 +1,32 @@
+use crate::api::types::{MatchDetailsResponse, PlayerStats};
+
+// Recent matches looked at per player - each one is a match-details request
+pub const RECENT_MATCHES: u32 = 3;
+
+/// Add up one player's kills, deaths, assists and wins over the given matches
+/// Matches without the player or without a scoreboard are skipped
+pub fn summarize(puuid: &str, matches: &[MatchDetailsResponse]) -> PlayerStats {
+    let mut stats = PlayerStats::default();
+
+    for details in matches {
+        let Some(player) = details.players.iter().flatten().find(|p| p.subject == puuid) else {
+            continue;
+        };
+        let Some(line) = &player.stats else { continue };
+
+        stats.matches += 1;
+        stats.kills += line.kills;
+        stats.deaths += line.deaths;
+        stats.assists += line.assists;
+
+        let won = details
+            .teams
+            .iter()
+            .flatten()
+            .any(|t| t.won && player.team_id.as_deref() == Some(t.team_id.as_str()));
+        if won {
+            stats.wins += 1;
+        }
+    }
+    stats
+}
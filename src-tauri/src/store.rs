@@ -0,0 +1,106 @@
+use crate::api::content::ContentCatalog;
+use crate::api::types::*;
+use crate::constants::{CURRENCY_KINGDOM_CREDITS, CURRENCY_RADIANITE, CURRENCY_VP};
+use std::collections::HashMap;
+
+/// Resolve a raw storefront into named offers priced in VP
+pub fn resolve_storefront(raw: StorefrontResponse, catalog: Option<&ContentCatalog>) -> Storefront {
+    let (daily_offers, daily_remaining_secs) = raw
+        .skins_panel_layout
+        .map(|panel| {
+            let offers = panel
+                .single_item_store_offers
+                .unwrap_or_default()
+                .iter()
+                .filter_map(|o| resolve_offer(o, &o.cost, catalog))
+                .collect();
+            let remaining = panel.single_item_offers_remaining_duration_in_seconds.unwrap_or(0);
+            (offers, remaining)
+        })
+        .unwrap_or_default();
+
+    let bundles = raw
+        .featured_bundle
+        .and_then(|f| f.bundles)
+        .unwrap_or_default()
+        .into_iter()
+        .map(|b| resolve_bundle(b, catalog))
+        .collect();
+
+    let night_market = raw.bonus_store.map(|bonus| NightMarket {
+        offers: bonus
+            .bonus_store_offers
+            .iter()
+            .filter_map(|o| {
+                Some(NightMarketOffer {
+                    offer: resolve_offer(&o.offer, &o.offer.cost, catalog)?,
+                    discount_percent: o.discount_percent,
+                    discounted_price: vp(&o.discount_costs),
+                })
+            })
+            .collect(),
+        remaining_secs: bonus.bonus_store_remaining_duration_in_seconds.unwrap_or(0),
+    });
+
+    Storefront {
+        daily_offers,
+        daily_remaining_secs,
+        bundles,
+        night_market,
+    }
+}
+
+pub fn resolve_wallet(raw: &WalletResponse) -> Wallet {
+    let balance = |id: &str| raw.balances.get(id).copied().unwrap_or(0);
+    Wallet {
+        valorant_points: balance(CURRENCY_VP),
+        radianite: balance(CURRENCY_RADIANITE),
+        kingdom_credits: balance(CURRENCY_KINGDOM_CREDITS),
+    }
+}
+
+fn vp(cost: &HashMap<String, u32>) -> u32 {
+    cost.get(CURRENCY_VP).copied().unwrap_or(0)
+}
+
+/// Single-item offers reward one skin level
+fn resolve_offer(
+    offer: &StoreOfferData,
+    cost: &HashMap<String, u32>,
+    catalog: Option<&ContentCatalog>,
+) -> Option<StoreOffer> {
+    let reward = offer.rewards.first()?;
+    Some(store_item(&reward.item_id, vp(cost), catalog))
+}
+
+fn resolve_bundle(bundle: StoreBundleData, catalog: Option<&ContentCatalog>) -> StoreBundle {
+    let items: Vec<StoreOffer> = bundle
+        .items
+        .iter()
+        .map(|i| store_item(&i.item.item_id, i.discounted_price, catalog))
+        .collect();
+
+    StoreBundle {
+        name: catalog.and_then(|c| c.bundle_name(&bundle.data_asset_id)),
+        bundle_id: bundle.data_asset_id,
+        base_price: bundle.items.iter().map(|i| i.base_price).sum(),
+        discounted_price: bundle.items.iter().map(|i| i.discounted_price).sum(),
+        items,
+        remaining_secs: bundle.duration_remaining_in_seconds.unwrap_or(0),
+    }
+}
+
+fn store_item(item_id: &str, price: u32, catalog: Option<&ContentCatalog>) -> StoreOffer {
+    StoreOffer {
+        item_id: item_id.to_string(),
+        name: catalog.and_then(|c| {
+            c.skin_name(item_id)
+                .or_else(|| c.buddy_name(item_id))
+                .or_else(|| c.spray_name(item_id))
+                .or_else(|| c.player_card_name(item_id))
+                .or_else(|| c.player_title_name(item_id))
+        }),
+        tier: catalog.and_then(|c| c.tier_name(item_id)),
+        price,
+    }
+}
@@ -0,0 +1,215 @@
+use crate::api::types::{PlayerData, TeamLayout};
+
+const PLAYING_TEAMS: [&str; 2] = ["Blue", "Red"];
+
+/// What a roster entry is doing in the match
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Slot {
+    Player,
+    Observer,
+    Coach,
+}
+
+#[derive(Debug)]
+pub struct RosterEntry {
+    pub team_id: String,
+    pub slot: Slot,
+    pub player: PlayerData,
+}
+
+/// Coregame roster grouped for the current mode
+#[derive(Debug, Default)]
+pub struct Roster {
+    pub layout: TeamLayout,
+    // Team the allies belong to - mine, or Blue when I'm spectating
+    pub ally_team: String,
+    pub allies: Vec<PlayerData>,
+    pub enemies: Vec<PlayerData>,
+    pub players: Vec<PlayerData>,
+    pub observers: Vec<PlayerData>,
+    pub coaches: Vec<PlayerData>,
+}
+
+/// Slot from the coregame flags - players on neither team are spectators too
+pub fn slot_for(team_id: &str, is_observer: bool, is_coach: bool) -> Slot {
+    if is_coach {
+        Slot::Coach
+    } else if is_observer || team_id == "Neutral" {
+        Slot::Observer
+    } else {
+        Slot::Player
+    }
+}
+
+/// Deathmatch puts every player on their own team, so it's detected by queue
+/// and, for custom games, by more than two distinct teams
+fn layout_for(queue_id: Option<&str>, team_ids: &[&str]) -> TeamLayout {
+    if queue_id == Some("deathmatch") {
+        return TeamLayout::FreeForAll;
+    }
+    let mut teams: Vec<&str> = team_ids.to_vec();
+    teams.sort_unstable();
+    teams.dedup();
+    if teams.len() > PLAYING_TEAMS.len() {
+        TeamLayout::FreeForAll
+    } else {
+        TeamLayout::Teams
+    }
+}
+
+/// Group the roster into allies/enemies or a free-for-all list, with observers
+/// and coaches kept apart
+pub fn group(entries: Vec<RosterEntry>, queue_id: Option<&str>) -> Roster {
+    let team_ids: Vec<&str> = entries
+        .iter()
+        .filter(|e| e.slot == Slot::Player)
+        .map(|e| e.team_id.as_str())
+        .collect();
+
+    let mut roster = Roster {
+        layout: layout_for(queue_id, &team_ids),
+        ..Default::default()
+    };
+
+    // Coaches sit with a team, so they also decide which side is "mine"
+    roster.ally_team = entries
+        .iter()
+        .find(|e| e.player.is_me && PLAYING_TEAMS.contains(&e.team_id.as_str()))
+        .map(|e| e.team_id.clone())
+        .unwrap_or_else(|| "Blue".into());
+
+    for entry in entries {
+        match entry.slot {
+            Slot::Observer => roster.observers.push(entry.player),
+            Slot::Coach => roster.coaches.push(entry.player),
+            Slot::Player if roster.layout == TeamLayout::FreeForAll => roster.players.push(entry.player),
+            Slot::Player if entry.team_id == roster.ally_team => roster.allies.push(entry.player),
+            Slot::Player => roster.enemies.push(entry.player),
+        }
+    }
+
+    roster.players.sort_by_key(|p| !p.is_me);
+    roster
+}
+
+/// Roster entry for someone without an agent - pregame observers and coaches
+pub fn bystander(puuid: &str, name: String, party: String, is_me: bool) -> PlayerData {
+    PlayerData {
+        puuid: puuid.to_string(),
+        name,
+        agent: String::new(),
+        locked: false,
+        party,
+        is_me,
+        rank_tier: 0,
+        rank_rr: 0,
+        level: 0,
+        stats: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(puuid: &str, team_id: &str, slot: Slot, is_me: bool) -> RosterEntry {
+        RosterEntry {
+            team_id: team_id.to_string(),
+            slot,
+            player: bystander(puuid, puuid.to_string(), String::new(), is_me),
+        }
+    }
+
+    fn player(puuid: &str, team_id: &str) -> RosterEntry {
+        entry(puuid, team_id, Slot::Player, false)
+    }
+
+    fn puuids(players: &[PlayerData]) -> Vec<&str> {
+        players.iter().map(|p| p.puuid.as_str()).collect()
+    }
+
+    #[test]
+    fn deathmatch_lists_everyone_with_me_first() {
+        // Every deathmatch player is on a team of their own
+        let entries = vec![
+            player("a", "team-a"),
+            player("b", "team-b"),
+            entry("me", "team-me", Slot::Player, true),
+            player("c", "team-c"),
+        ];
+        let roster = group(entries, Some("deathmatch"));
+
+        assert_eq!(roster.layout, TeamLayout::FreeForAll);
+        assert_eq!(puuids(&roster.players), ["me", "a", "b", "c"]);
+        assert!(roster.allies.is_empty());
+        assert!(roster.enemies.is_empty());
+    }
+
+    #[test]
+    fn custom_free_for_all_is_detected_by_team_count() {
+        let entries = vec![
+            entry("me", "team-me", Slot::Player, true),
+            player("a", "team-a"),
+            player("b", "team-b"),
+        ];
+        let roster = group(entries, None);
+
+        assert_eq!(roster.layout, TeamLayout::FreeForAll);
+        assert_eq!(puuids(&roster.players), ["me", "a", "b"]);
+    }
+
+    #[test]
+    fn team_deathmatch_splits_by_my_team() {
+        let entries = vec![
+            player("a", "Blue"),
+            player("b", "Red"),
+            entry("me", "Red", Slot::Player, true),
+            player("c", "Blue"),
+        ];
+        let roster = group(entries, Some("hurm"));
+
+        assert_eq!(roster.layout, TeamLayout::Teams);
+        assert_eq!(roster.ally_team, "Red");
+        assert_eq!(puuids(&roster.allies), ["b", "me"]);
+        assert_eq!(puuids(&roster.enemies), ["a", "c"]);
+        assert!(roster.players.is_empty());
+    }
+
+    #[test]
+    fn custom_game_keeps_observers_and_coaches_apart() {
+        let entries = vec![
+            player("a", "Blue"),
+            player("b", "Red"),
+            // Observers show up on a Neutral team, or flagged on a playing one
+            entry("caster", "Neutral", slot_for("Neutral", false, false), false),
+            entry("spectator", "Blue", slot_for("Blue", true, false), false),
+            // Coaching Red makes Red my side
+            entry("me", "Red", slot_for("Red", false, true), true),
+            entry("coach", "Blue", slot_for("Blue", false, true), false),
+        ];
+        let roster = group(entries, None);
+
+        // Neither the Neutral team nor the coaches turn this into a free-for-all
+        assert_eq!(roster.layout, TeamLayout::Teams);
+        assert_eq!(roster.ally_team, "Red");
+        assert_eq!(puuids(&roster.allies), ["b"]);
+        assert_eq!(puuids(&roster.enemies), ["a"]);
+        assert_eq!(puuids(&roster.observers), ["caster", "spectator"]);
+        assert_eq!(puuids(&roster.coaches), ["me", "coach"]);
+    }
+
+    #[test]
+    fn spectating_shows_blue_as_allies() {
+        let entries = vec![
+            entry("me", "Neutral", slot_for("Neutral", true, false), true),
+            player("a", "Blue"),
+            player("b", "Red"),
+        ];
+        let roster = group(entries, None);
+
+        assert_eq!(roster.ally_team, "Blue");
+        assert_eq!(puuids(&roster.allies), ["a"]);
+        assert_eq!(puuids(&roster.enemies), ["b"]);
+        assert_eq!(puuids(&roster.observers), ["me"]);
+    }
+}
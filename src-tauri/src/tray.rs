@@ -0,0 +1,131 @@
+use crate::overlay;
+use crate::settings;
+use crate::state::AppState;
+use tauri::menu::{CheckMenuItem, Menu, MenuEvent, MenuItem, PredefinedMenuItem};
+use tauri::tray::TrayIconBuilder;
+use tauri::{AppHandle, Emitter, Manager};
+use tauri_plugin_opener::OpenerExt;
+
+const TRAY_ID: &str = "main";
+
+/// Build the tray icon - called once from setup
+pub fn init(app: &AppHandle) -> tauri::Result<()> {
+    let mut builder = TrayIconBuilder::with_id(TRAY_ID)
+        .tooltip(tooltip(app))
+        .menu(&build_menu(app)?)
+        .show_menu_on_left_click(true)
+        .on_menu_event(on_menu_event);
+
+    if let Some(icon) = app.default_window_icon() {
+        builder = builder.icon(icon.clone());
+    }
+    builder.build(app)?;
+    Ok(())
+}
+
+/// Update the tooltip and menu after a connection, phase or settings change
+pub fn refresh(app: &AppHandle) {
+    let Some(tray) = app.tray_by_id(TRAY_ID) else { return };
+
+    let _ = tray.set_tooltip(Some(tooltip(app)));
+    match build_menu(app) {
+        Ok(menu) => {
+            let _ = tray.set_menu(Some(menu));
+        }
+        Err(e) => tracing::warn!("Failed to rebuild tray menu: {}", e),
+    }
+}
+
+fn tooltip(app: &AppHandle) -> String {
+    let state = app.state::<AppState>();
+    let phase = state.phase.read().clone();
+
+    let status = if !*state.api.connected.read() || phase == "disconnected" {
+        "Not connected"
+    } else {
+        match phase.as_str() {
+            "queuing" => "Connected - In queue",
+            "pregame" => "Connected - Agent select",
+            "ingame" => "Connected - In game",
+            _ => "Connected - In lobby",
+        }
+    };
+    format!("VALORANT Tracker\n{}", status)
+}
+
+fn build_menu(app: &AppHandle) -> tauri::Result<Menu<tauri::Wry>> {
+    let state = app.state::<AppState>();
+    let settings = state.settings.get();
+    let click_through = *state.click_through.read();
+
+    // Auto-lock can only be switched on once an agent was picked in the settings panel
+    let auto_lock = match settings.auto_lock_agent.as_deref() {
+        Some(agent) => CheckMenuItem::with_id(
+            app,
+            "auto_lock",
+            format!("Auto-Lock: {}", capitalize(agent)),
+            true,
+            settings.auto_lock_enabled,
+            None::<&str>,
+        )?,
+        None => CheckMenuItem::with_id(app, "auto_lock", "Auto-Lock: No agent selected", false, false, None::<&str>)?,
+    };
+
+    Menu::with_items(
+        app,
+        &[
+            &MenuItem::with_id(app, "toggle_overlay", "Show / Hide Overlay", true, None::<&str>)?,
+            &CheckMenuItem::with_id(app, "click_through", "Click-Through", true, click_through, None::<&str>)?,
+            &auto_lock,
+            &PredefinedMenuItem::separator(app)?,
+            &MenuItem::with_id(app, "reconnect", "Reconnect", true, None::<&str>)?,
+            &MenuItem::with_id(app, "open_logs", "Open Logs Folder", true, None::<&str>)?,
+            &MenuItem::with_id(app, "check_updates", "Check for Updates", true, None::<&str>)?,
+            &PredefinedMenuItem::separator(app)?,
+            &MenuItem::with_id(app, "quit", "Quit", true, None::<&str>)?,
+        ],
+    )
+}
+
+fn on_menu_event(app: &AppHandle, event: MenuEvent) {
+    match event.id().as_ref() {
+        "toggle_overlay" => overlay::toggle_visible(app),
+        "click_through" => overlay::toggle_click_through(app),
+        "auto_lock" => {
+            let state = app.state::<AppState>();
+            let updated = state.settings.modify(|s| s.auto_lock_enabled = !s.auto_lock_enabled);
+            settings::changed(app, &updated);
+        }
+        // Connection state lives in the frontend store, let it run its reconnect flow
+        "reconnect" => {
+            let _ = app.emit("reconnect-requested", ());
+        }
+        "open_logs" => open_logs(app),
+        // The update button is in the overlay header
+        "check_updates" => {
+            if let Some(window) = app.get_webview_window("main") {
+                let _ = window.show();
+                let _ = window.set_focus();
+            }
+            let _ = app.emit("check-for-updates", ());
+        }
+        "quit" => app.exit(0),
+        _ => {}
+    }
+}
+
+fn open_logs(app: &AppHandle) {
+    let Ok(dir) = app.path().app_log_dir() else { return };
+    let _ = std::fs::create_dir_all(&dir);
+    if let Err(e) = app.opener().open_path(dir.to_string_lossy(), None::<&str>) {
+        tracing::warn!("Failed to open logs folder: {}", e);
+    }
+}
+
+fn capitalize(s: &str) -> String {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
@@ -1,5 +1,5 @@
-import { useEffect, useRef } from "react";
-import { getCurrentWindow } from "@tauri-apps/api/window";
+import { useEffect } from "react";
+import { listen } from "@tauri-apps/api/event";
 import { Header } from "./components/Header";
 import { Footer } from "./components/Footer";
 import { WaitingState } from "./components/WaitingState";
@@ -10,42 +10,65 @@ import { WeaponOverlay } from "./components/WeaponOverlay";
 import { useGameStore } from "./stores/gameStore";
 import { useSettingsStore } from "./stores/settingsStore";
 import { useAssetsStore } from "./stores/assetsStore";
+import { usePanelStore } from "./stores/panelStore";
+import { useUpdateStore } from "./stores/updateStore";
+import type { EnrichmentEvent, HotkeyAction, HotkeyError, Settings } from "./lib/types";
 
-let shortcutRegistered = false;
+// Browsers cap the number of live contexts, so one is shared by every chime
+let audioContext: AudioContext | null = null;
+
+// Short two-tone chime so a found match is noticed while alt-tabbed
+const playMatchFoundSound = () => {
+  if (!audioContext) audioContext = new AudioContext();
+  const ctx = audioContext;
+  [660, 880].forEach((freq, i) => {
+    const osc = ctx.createOscillator();
+    const gain = ctx.createGain();
+    osc.frequency.value = freq;
+    gain.gain.value = 0.15;
+    osc.connect(gain).connect(ctx.destination);
+    osc.start(ctx.currentTime + i * 0.18);
+    osc.stop(ctx.currentTime + i * 0.18 + 0.15);
+  });
+};
 
 function App() {
-  const { initialize, fetchGameState, gameState } = useGameStore();
-  const { registerHotkey, restoreWindowPosition, saveCurrentPosition } = useSettingsStore();
+  const { initialize, fetchGameState, reconnect, applyEnrichment, gameState } = useGameStore();
+  const { loadSettings, applySettings, addHotkeyError, overlay } = useSettingsStore();
   const { loadAssets } = useAssetsStore();
-  const positionInitialized = useRef(false);
 
   useEffect(() => {
     initialize();
     loadAssets();
 
-    if (!shortcutRegistered) {
-      shortcutRegistered = true;
-      registerHotkey();
-    }
+    // Settings, window position and global hotkeys are owned by the backend
+    loadSettings();
+    const settingsChanged = listen<Settings>("settings-changed", (e) => applySettings(e.payload));
+    const hotkeyError = listen<HotkeyError>("hotkey-error", (e) => addHotkeyError(e.payload));
+    const clickThrough = listen<boolean>("click-through-changed", (e) => useSettingsStore.setState({ clickThrough: e.payload }));
+    const hotkey = listen<HotkeyAction>("hotkey", (e) => {
+      if (e.payload === "refresh_state") fetchGameState();
+      if (e.payload === "cycle_panels") usePanelStore.getState().cyclePanel();
+    });
 
-    if (!positionInitialized.current) {
-      positionInitialized.current = true;
-      restoreWindowPosition();
-    }
+    // Tray menu actions that need the frontend stores
+    const reconnectRequested = listen("reconnect-requested", () => reconnect());
+    const checkForUpdates = listen("check-for-updates", () => useUpdateStore.getState().checkForUpdate());
 
-    let unlisten: (() => void) | null = null;
-    const setupMoveListener = async () => {
-      const win = getCurrentWindow();
-      unlisten = await win.onMoved(() => {
-        saveCurrentPosition();
-      });
-    };
-    setupMoveListener();
+    const matchFound = listen("match-found", playMatchFoundSound);
+    const enrichment = listen<EnrichmentEvent>("game-state-enrichment", (e) => applyEnrichment(e.payload));
 
     const interval = setInterval(fetchGameState, 3000);
     return () => {
       clearInterval(interval);
-      if (unlisten) unlisten();
+      settingsChanged.then((off) => off());
+      hotkeyError.then((off) => off());
+      clickThrough.then((off) => off());
+      hotkey.then((off) => off());
+      reconnectRequested.then((off) => off());
+      checkForUpdates.then((off) => off());
+      matchFound.then((off) => off());
+      enrichment.then((off) => off());
     };
   }, []);
 
@@ -61,7 +84,7 @@ function App() {
   };
 
   return (
-    <div className="h-full flex bg-dark">
+    <div className="h-full flex bg-dark transition-opacity" style={{ opacity: overlay.opacity }}>
       {/* Main content */}
       <div className="relative flex-1 flex flex-col p-4 pl-5 min-w-0">
         <Header />
@@ -0,0 +1,73 @@
+import { useEffect } from "react";
+import { listen } from "@tauri-apps/api/event";
+import { invoke } from "@tauri-apps/api/core";
+import { useGameStore } from "./stores/gameStore";
+import { useSettingsStore } from "./stores/settingsStore";
+import { useAssetsStore } from "./stores/assetsStore";
+import { AGENT_COLORS, RANK_TIERS, getPartyColor } from "./lib/constants";
+import type { EnrichmentEvent, GameState, PlayerData, Settings } from "./lib/types";
+
+// Compact enemy strip shown by the backend while a match is running.
+// It doesn't poll - the main window's fetches are mirrored as "game-state".
+function Hud() {
+  const { gameState, applyEnrichment } = useGameStore();
+  const { loadSettings, applySettings, overlay } = useSettingsStore();
+  const { loadAssets, getAgentIcon } = useAssetsStore();
+
+  useEffect(() => {
+    loadAssets();
+    loadSettings();
+
+    const gameStateEvent = listen<GameState>("game-state", (e) => useGameStore.setState({ gameState: e.payload }));
+    const enrichment = listen<EnrichmentEvent>("game-state-enrichment", (e) => applyEnrichment(e.payload));
+    const settingsChanged = listen<Settings>("settings-changed", (e) => applySettings(e.payload));
+
+    return () => {
+      gameStateEvent.then((off) => off());
+      enrichment.then((off) => off());
+      settingsChanged.then((off) => off());
+    };
+  }, []);
+
+  const startDrag = async (e: React.MouseEvent) => {
+    if (e.button !== 0) return;
+    try {
+      // The HUD has no window permissions, the backend moves it
+      await invoke("drag_hud");
+    } catch (err) {
+      console.error("Drag failed:", err);
+    }
+  };
+
+  const enemies: PlayerData[] = gameState.layout === "free_for_all" ? gameState.players.filter((p) => !p.is_me) : gameState.enemies;
+
+  return (
+    <div className="h-full flex items-center gap-1.5 px-2 bg-dark/85 rounded-lg cursor-move select-none" style={{ opacity: overlay.opacity }} onMouseDown={startDrag}>
+      {gameState.score_ally !== null && gameState.score_enemy !== null && (
+        <span className="px-1.5 text-xs font-black">
+          <span className="text-accent-cyan">{gameState.score_ally}</span>
+          <span className="text-dim"> : </span>
+          <span className="text-accent-red">{gameState.score_enemy}</span>
+        </span>
+      )}
+      {enemies.map((player) => {
+        const agentColor = AGENT_COLORS[player.agent?.toLowerCase()] || "#768079";
+        const [rankName, rankColor] = RANK_TIERS[player.rank_tier] || ["", "#768079"];
+        const partyColor = getPartyColor(player.party);
+        const agentIcon = player.agent ? getAgentIcon(player.agent) : null;
+
+        return (
+          <div key={player.puuid} className="relative flex-1 min-w-0 flex items-center gap-1.5 h-11 pl-2 pr-1.5 rounded-md bg-card" title={player.name}>
+            {partyColor && <div className="absolute left-0.5 top-1/2 -translate-y-1/2 w-[3px] h-7 rounded-sm" style={{ backgroundColor: partyColor }} />}
+            {agentIcon ? <img src={agentIcon} alt={player.agent} className="w-7 h-7 rounded-full object-cover" style={{ border: `1.5px solid ${agentColor}60` }} /> : <div className="w-7 h-7 rounded-full bg-dim/40" />}
+            <span className="text-[10px] font-semibold truncate" style={{ color: rankColor }}>
+              {player.rank_tier > 0 ? rankName : "—"}
+            </span>
+          </div>
+        );
+      })}
+    </div>
+  );
+}
+
+export default Hud;
@@ -0,0 +1,26 @@
+import type { PlayerData } from "../lib/types";
+import { PlayerCard } from "./PlayerCard";
+
+interface Props {
+  title: string;
+  players: PlayerData[];
+}
+
+/** Observers and coaches, listed under the teams */
+export function BystanderList({ title, players }: Props) {
+  if (players.length === 0) return null;
+
+  return (
+    <>
+      <div className="h-px bg-border my-3" />
+      <div className="mb-1">
+        <span className="text-[10px] font-semibold text-dim">{title}</span>
+      </div>
+      <div className="space-y-1">
+        {players.map((player) => (
+          <PlayerCard key={player.puuid} player={player} />
+        ))}
+      </div>
+    </>
+  );
+}
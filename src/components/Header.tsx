@@ -9,7 +9,7 @@ import { useI18n } from "../lib/i18n";
 
 export function Header() {
   const { connected, region, gameState, reconnect } = useGameStore();
-  const { hotkey } = useSettingsStore();
+  const { hotkeys, clickThrough } = useSettingsStore();
   const { getMapSplash } = useAssetsStore();
   const { updateAvailable, updateVersion, isDownloading, downloadProgress, checkForUpdate, downloadAndInstall } = useUpdateStore();
   const { t } = useI18n();
@@ -62,12 +62,17 @@ export function Header() {
         </svg>
         <div>
           <h1 className="text-base font-black text-primary tracking-tight">VALORANT</h1>
-          <span className="text-[9px] text-dim">{hotkey} Overlay</span>
+          <span className="text-[9px] text-dim">{hotkeys.toggle_overlay ? `${hotkeys.toggle_overlay} Overlay` : "Overlay"}</span>
         </div>
       </div>
 
       <div className="relative z-10 flex items-center gap-2">
         {region && <span className="text-[10px] font-semibold text-accent-cyan pointer-events-none">{region}</span>}
+        {clickThrough && (
+          <span className="text-[9px] text-dim pointer-events-none" title={hotkeys.toggle_click_through ?? undefined}>
+            {t("header.clickThrough")}
+          </span>
+        )}
         <StatusIndicator status={status} />
 
         {/* Update Button */}
@@ -1,4 +1,5 @@
 import { PlayerCard } from "./PlayerCard";
+import { BystanderList } from "./BystanderList";
 import { useGameStore } from "../stores/gameStore";
 import { useI18n } from "../lib/i18n";
 
@@ -11,31 +12,82 @@ export function IngameState() {
       {/* Header */}
       <div className="flex items-center justify-between mb-2">
         <span className="text-sm font-black text-accent-red">LIVE</span>
+        {gameState.score_ally !== null && gameState.score_enemy !== null && (
+          <span className="text-sm font-black">
+            <span className="text-accent-cyan">{gameState.score_ally}</span>
+            <span className="text-dim"> - </span>
+            <span className="text-accent-red">{gameState.score_enemy}</span>
+          </span>
+        )}
         {gameState.map_name && <span className="text-xs font-semibold text-secondary">{gameState.map_name}</span>}
       </div>
 
-      {/* Allies */}
-      <div className="mb-1">
-        <span className="text-[10px] font-semibold text-accent-cyan">{t("ingame.allies")}</span>
-      </div>
-      <div className="space-y-1 mb-3">
-        {gameState.allies.map((player) => (
-          <PlayerCard key={player.puuid} player={player} />
-        ))}
-      </div>
+      {/* Mode */}
+      {gameState.mode_name && (
+        <div className="flex items-center gap-1 mb-2">
+          <span className="text-[10px] text-secondary">{gameState.mode_name}</span>
+          {gameState.is_ranked && <span className="text-[10px] font-semibold text-accent-cyan">RANKED</span>}
+          {gameState.is_custom && <span className="text-[10px] font-semibold text-dim">CUSTOM</span>}
+          {gameState.server && (
+            <span className="ml-auto text-[10px] text-dim" title={gameState.server.data_center}>{gameState.server.location}</span>
+          )}
+        </div>
+      )}
 
-      {/* Divider */}
-      <div className="h-px bg-border my-3" />
+      {/* Round and side */}
+      {gameState.round !== null && (
+        <div className="flex items-center justify-between px-2 py-1 bg-card rounded-md mb-3">
+          <span className="text-[10px] text-secondary">R{gameState.round}</span>
+          {gameState.side && (
+            <span className={`text-[10px] font-semibold ${gameState.side.includes("SALDIRAN") ? "text-accent-red" : "text-accent-cyan"}`}>
+              {gameState.side.includes("SALDIRAN") ? "ATK" : "DEF"}
+            </span>
+          )}
+        </div>
+      )}
 
-      {/* Enemies */}
-      <div className="mb-1">
-        <span className="text-[10px] font-semibold text-accent-red">{t("ingame.enemies")}</span>
-      </div>
-      <div className="space-y-1">
-        {gameState.enemies.map((player) => (
-          <PlayerCard key={player.puuid} player={player} />
-        ))}
-      </div>
+      {gameState.layout === "free_for_all" ? (
+        <>
+          {/* Free-for-all */}
+          <div className="mb-1">
+            <span className="text-[10px] font-semibold text-accent-red">{t("ingame.players")}</span>
+          </div>
+          <div className="space-y-1">
+            {gameState.players.map((player) => (
+              <PlayerCard key={player.puuid} player={player} />
+            ))}
+          </div>
+        </>
+      ) : (
+        <>
+          {/* Allies */}
+          <div className="mb-1">
+            <span className="text-[10px] font-semibold text-accent-cyan">{t("ingame.allies")}</span>
+          </div>
+          <div className="space-y-1 mb-3">
+            {gameState.allies.map((player) => (
+              <PlayerCard key={player.puuid} player={player} />
+            ))}
+          </div>
+
+          {/* Divider */}
+          <div className="h-px bg-border my-3" />
+
+          {/* Enemies */}
+          <div className="mb-1">
+            <span className="text-[10px] font-semibold text-accent-red">{t("ingame.enemies")}</span>
+          </div>
+          <div className="space-y-1">
+            {gameState.enemies.map((player) => (
+              <PlayerCard key={player.puuid} player={player} />
+            ))}
+          </div>
+        </>
+      )}
+
+      {/* Observers and coaches */}
+      <BystanderList title={t("ingame.observers")} players={gameState.observers} />
+      <BystanderList title={t("ingame.coaches")} players={gameState.coaches} />
     </div>
   );
 }
@@ -1,5 +1,5 @@
 import type { PlayerData } from "../lib/types";
-import { AGENT_COLORS, RANK_TIERS, PARTY_COLORS } from "../lib/constants";
+import { AGENT_COLORS, RANK_TIERS, getPartyColor } from "../lib/constants";
 import { useI18n } from "../lib/i18n";
 import { useAssetsStore } from "../stores/assetsStore";
 import { usePanelStore } from "../stores/panelStore";
@@ -15,10 +15,13 @@ export function PlayerCard({ player }: Props) {
 
   const agentColor = AGENT_COLORS[player.agent?.toLowerCase()] || "#768079";
   const [rankName, rankColor] = RANK_TIERS[player.rank_tier] || ["", "#768079"];
-  const partyIndex = player.party.startsWith("Grup-") || player.party.startsWith("Group-") ? parseInt(player.party.split("-")[1]) - 1 : -1;
-  const partyColor = partyIndex >= 0 ? PARTY_COLORS[partyIndex % 4] : null;
+  const partyColor = getPartyColor(player.party);
   const agentIcon = player.agent ? getAgentIcon(player.agent) : null;
 
+  // K/D over the last few matches, deaths floored at 1 so a clean game isn't infinite
+  const stats = player.stats && player.stats.matches > 0 ? player.stats : null;
+  const kd = stats ? (stats.kills / Math.max(stats.deaths, 1)).toFixed(2) : null;
+
   const statusColor = player.locked ? "bg-success" : player.agent ? "bg-warning" : "bg-dim";
 
   return (
@@ -51,6 +54,13 @@ export function PlayerCard({ player }: Props) {
       {/* Name */}
       <span className={`flex-1 text-xs font-semibold truncate ${player.is_me ? "text-accent-gold" : "text-primary"}`}>{player.name}</span>
 
+      {/* Recent form */}
+      {stats && (
+        <span className="text-[10px] text-dim mr-2" title={t("player.recentMatches").replace("{n}", String(stats.matches))}>
+          {t("player.kd")} {kd} · {stats.wins}/{stats.matches}{t("player.wins")}
+        </span>
+      )}
+
       {/* Level */}
       {player.level > 0 && (
         <span className="text-[10px] text-dim mr-2">
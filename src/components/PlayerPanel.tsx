@@ -7,12 +7,26 @@ import { WEAPON_NAMES, AGENT_COLORS, RANK_TIERS } from "../lib/constants";
 
 interface WeaponSkin {
   weapon_id: string;
+  weapon_name: string | null;
   skin_id: string;
+  skin_name: string | null;
+  level_id: string | null;
   chroma_id: string | null;
+  chroma_name: string | null;
+  tier: string | null;
+  buddy_id: string | null;
+  buddy_name: string | null;
+}
+interface NamedItem {
+  id: string;
+  name: string | null;
 }
 interface PlayerSkinData {
   puuid: string;
   skins: WeaponSkin[];
+  sprays: NamedItem[];
+  player_card: NamedItem | null;
+  player_title: NamedItem | null;
 }
 interface SkinInfo {
   name: string;
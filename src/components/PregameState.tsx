@@ -1,4 +1,5 @@
 import { PlayerCard } from "./PlayerCard";
+import { BystanderList } from "./BystanderList";
 import { useGameStore } from "../stores/gameStore";
 import { useI18n } from "../lib/i18n";
 
@@ -20,6 +21,9 @@ export function PregameState() {
         <div className="flex items-center justify-between px-2 py-1 bg-card rounded-md mb-3">
           {gameState.map_name && <span className="text-[11px] font-semibold text-primary">{gameState.map_name}</span>}
           {gameState.mode_name && <span className="text-[10px] text-secondary">{gameState.mode_name}</span>}
+          {gameState.server && (
+            <span className="text-[10px] text-dim" title={gameState.server.data_center}>{gameState.server.location}</span>
+          )}
         </div>
       )}
 
@@ -34,6 +38,10 @@ export function PregameState() {
           <PlayerCard key={player.puuid} player={player} />
         ))}
       </div>
+
+      {/* Observers and coaches */}
+      <BystanderList title={t("ingame.observers")} players={gameState.observers} />
+      <BystanderList title={t("ingame.coaches")} players={gameState.coaches} />
     </div>
   );
 }
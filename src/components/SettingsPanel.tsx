@@ -4,12 +4,19 @@ import { useSettingsStore } from "../stores/settingsStore";
 import { useAssetsStore } from "../stores/assetsStore";
 import { useI18n } from "../lib/i18n";
 import { AGENTS, AGENT_COLORS } from "../lib/constants";
+import type { HotkeyAction, LogLevel, OverlaySettings } from "../lib/types";
 
 const STANDALONE_KEYS = ["F1", "F2", "F3", "F4", "F5", "F6", "F7", "F8", "F9", "F10", "F11", "F12", "Insert", "Delete", "Home", "End", "PageUp", "PageDown", "Pause", "ScrollLock", "NumLock"];
 
 const BLOCKED_KEYS = ["Escape", "Tab", "CapsLock", "Enter", "Backspace", "Space"];
 const MODIFIERS = ["Control", "Alt", "Shift", "Meta"];
 
+const HOTKEY_ACTIONS: HotkeyAction[] = ["toggle_overlay", "toggle_click_through", "refresh_state", "cycle_panels"];
+
+const LOG_LEVELS: LogLevel[] = ["error", "warn", "info", "debug", "trace"];
+
+const OVERLAY_RULES: (keyof Omit<OverlaySettings, "opacity">)[] = ["show_on_pregame", "hide_on_match_start", "hide_on_idle", "show_hud"];
+
 function buildHotkeyString(e: KeyboardEvent): string | null {
   const key = e.key;
   if (BLOCKED_KEYS.includes(key) || MODIFIERS.includes(key)) return null;
@@ -31,22 +38,31 @@ function buildHotkeyString(e: KeyboardEvent): string | null {
 
 export function SettingsPanel() {
   const { autoLockAgent, setAutoLock } = useGameStore();
-  const { hotkey, setHotkey, pauseHotkey, resumeHotkey } = useSettingsStore();
+  const { hotkeys, hotkeyErrors, setHotkey, pauseHotkey, resumeHotkey, overlay, setOverlay, clickThrough, setClickThrough, logLevel, setLogLevel, exportDiagnostics } = useSettingsStore();
   const { getAgentIcon } = useAssetsStore();
   const { locale, setLocale, t } = useI18n();
-  const [recording, setRecording] = useState(false);
+  const [recording, setRecording] = useState<HotkeyAction | null>(null);
   const [recordingDisplay, setRecordingDisplay] = useState("");
   const [hotkeyError, setHotkeyError] = useState<string | null>(null);
+  const [exporting, setExporting] = useState(false);
+  const [exportResult, setExportResult] = useState<string | null>(null);
 
-  const startRecording = useCallback(async () => {
+  const handleExport = useCallback(async () => {
+    setExporting(true);
+    const path = await exportDiagnostics();
+    setExportResult(path ? t("settings.diagnostics.exported") : t("settings.diagnostics.failed"));
+    setExporting(false);
+  }, [exportDiagnostics, t]);
+
+  const startRecording = useCallback(async (action: HotkeyAction) => {
     await pauseHotkey();
-    setRecording(true);
+    setRecording(action);
     setRecordingDisplay("");
     setHotkeyError(null);
   }, [pauseHotkey]);
 
   const cancelRecording = useCallback(async () => {
-    setRecording(false);
+    setRecording(null);
     setRecordingDisplay("");
     await resumeHotkey();
   }, [resumeHotkey]);
@@ -55,6 +71,7 @@ export function SettingsPanel() {
     async (e: KeyboardEvent) => {
       e.preventDefault();
       e.stopPropagation();
+      if (!recording) return;
       if (e.key === "Escape") {
         cancelRecording();
         return;
@@ -73,15 +90,15 @@ export function SettingsPanel() {
         setTimeout(() => setHotkeyError(null), 2000);
         return;
       }
-      setRecording(false);
+      setRecording(null);
       setRecordingDisplay("");
-      const success = await setHotkey(hotkeyString);
+      const success = await setHotkey(recording, hotkeyString);
       if (!success) {
         setHotkeyError(locale === "tr" ? "Kayıt başarısız" : "Failed");
         setTimeout(() => setHotkeyError(null), 2000);
       }
     },
-    [setHotkey, locale, cancelRecording]
+    [recording, setHotkey, locale, cancelRecording]
   );
 
   useEffect(() => {
@@ -107,23 +124,79 @@ export function SettingsPanel() {
             </button>
           </div>
         </div>
-        {/* Hotkey */}
+        {/* Hotkeys */}
         <div>
           <label className="text-[10px] text-dim block mb-1.5">{t("settings.hotkey")}</label>
-          {recording ? (
-            <div className="flex gap-1.5">
-              <div className="flex-1 h-8 rounded text-[11px] font-bold border bg-accent-cyan/20 border-accent-cyan text-accent-cyan animate-pulse flex items-center justify-center">{recordingDisplay || "..."}</div>
-              <button onClick={cancelRecording} className="px-3 h-8 rounded text-[10px] font-semibold border border-error/50 text-error hover:bg-error/10 transition-all">
-                {locale === "tr" ? "İptal" : "Cancel"}
-              </button>
-            </div>
-          ) : (
-            <button onClick={startRecording} className="w-full h-8 rounded text-[11px] font-bold border bg-card border-border text-primary hover:bg-card-hover transition-all">
-              {hotkey}
-            </button>
-          )}
+          <div className="space-y-1.5">
+            {HOTKEY_ACTIONS.map((action) => {
+              const binding = hotkeys[action];
+              const failed = hotkeyErrors.find((e) => e.action === action);
+              return (
+                <div key={action} className="flex items-center gap-1.5">
+                  <span className="w-24 shrink-0 text-[10px] text-secondary truncate">{t(`settings.hotkey.${action}`)}</span>
+                  {recording === action ? (
+                    <>
+                      <div className="flex-1 h-7 rounded text-[11px] font-bold border bg-accent-cyan/20 border-accent-cyan text-accent-cyan animate-pulse flex items-center justify-center">{recordingDisplay || "..."}</div>
+                      <button onClick={cancelRecording} className="px-2 h-7 rounded text-[10px] font-semibold border border-error/50 text-error hover:bg-error/10 transition-all">
+                        {locale === "tr" ? "İptal" : "Cancel"}
+                      </button>
+                    </>
+                  ) : (
+                    <>
+                      <button onClick={() => startRecording(action)} disabled={recording !== null} title={failed?.message} className={`flex-1 h-7 rounded text-[11px] font-bold border bg-card hover:bg-card-hover transition-all ${failed ? "border-error/50 text-error" : "border-border text-primary"}`}>
+                        {binding || <span className="text-dim font-normal">{t("settings.hotkeyUnbound")}</span>}
+                      </button>
+                      {binding && (
+                        <button onClick={() => setHotkey(action, null)} disabled={recording !== null} className="w-7 h-7 rounded text-[10px] border border-border text-dim hover:text-error hover:border-error/50 transition-all">
+                          ✕
+                        </button>
+                      )}
+                    </>
+                  )}
+                </div>
+              );
+            })}
+          </div>
           {hotkeyError && <p className="text-[9px] text-error mt-1">{hotkeyError}</p>}
         </div>
+        {/* Overlay */}
+        <div>
+          <label className="text-[10px] text-dim block mb-1.5">{t("settings.overlay")}</label>
+          <div className="space-y-1">
+            {[
+              { key: "click_through", checked: clickThrough, onChange: () => setClickThrough(!clickThrough) },
+              ...OVERLAY_RULES.map((rule) => ({ key: rule, checked: overlay[rule], onChange: () => setOverlay({ [rule]: !overlay[rule] }) })),
+            ].map(({ key, checked, onChange }) => (
+              <label key={key} className="flex items-center gap-2 text-[10px] text-secondary cursor-pointer">
+                <input type="checkbox" checked={checked} onChange={onChange} className="accent-accent-cyan" />
+                {t(`settings.overlay.${key}`)}
+              </label>
+            ))}
+            <div className="flex items-center gap-2 pt-1">
+              <span className="w-24 shrink-0 text-[10px] text-secondary">{t("settings.overlay.opacity")}</span>
+              <input type="range" min={20} max={100} step={5} value={Math.round(overlay.opacity * 100)} onChange={(e) => setOverlay({ opacity: Number(e.target.value) / 100 })} className="flex-1 accent-accent-cyan" />
+              <span className="w-8 text-right text-[10px] text-dim">{Math.round(overlay.opacity * 100)}%</span>
+            </div>
+          </div>
+        </div>
+        {/* Diagnostics */}
+        <div>
+          <label className="text-[10px] text-dim block mb-1.5">{t("settings.diagnostics")}</label>
+          <div className="flex items-center gap-2">
+            <span className="w-24 shrink-0 text-[10px] text-secondary">{t("settings.diagnostics.logLevel")}</span>
+            <select value={logLevel} onChange={(e) => setLogLevel(e.target.value as LogLevel)} className="flex-1 bg-card border border-border rounded px-2 py-1 text-[10px] text-secondary">
+              {LOG_LEVELS.map((level) => (
+                <option key={level} value={level}>
+                  {level}
+                </option>
+              ))}
+            </select>
+          </div>
+          <button onClick={handleExport} disabled={exporting} className="mt-1.5 w-full px-2 py-1 text-[10px] rounded border border-border bg-card/30 text-secondary hover:bg-card-hover hover:border-accent-cyan transition-colors disabled:opacity-50">
+            {exporting ? t("settings.diagnostics.exporting") : t("settings.diagnostics.export")}
+          </button>
+          {exportResult && <p className="text-[9px] text-dim mt-1">{exportResult}</p>}
+        </div>
       </div>
 
       {/* Agent Selection - Full remaining height */}
@@ -1,9 +1,13 @@
 import { useGameStore } from "../stores/gameStore";
 import { useI18n } from "../lib/i18n";
 
+const formatQueueTime = (secs: number) => `${Math.floor(secs / 60)}:${String(secs % 60).padStart(2, "0")}`;
+
 export function WaitingState() {
   const autoLockAgent = useGameStore((s) => s.autoLockAgent);
+  const gameState = useGameStore((s) => s.gameState);
   const { t } = useI18n();
+  const queuing = gameState.state === "queuing";
 
   return (
     <div className="flex flex-col items-center justify-center flex-1 px-4">
@@ -13,8 +17,20 @@ export function WaitingState() {
         <div className="absolute inset-4 bg-accent-cyan rounded-full animate-pulse" />
       </div>
 
-      <h2 className="text-base font-semibold text-primary mb-1">{t("waiting.title")}</h2>
-      <p className="text-xs text-dim">{t("waiting.desc")}</p>
+      {queuing ? (
+        <>
+          <h2 className="text-base font-semibold text-primary mb-1">{t("waiting.queuing")}</h2>
+          <p className="text-xs text-dim">
+            {gameState.mode_name}
+            {gameState.queue_elapsed_secs !== null && ` · ${formatQueueTime(gameState.queue_elapsed_secs)}`}
+          </p>
+        </>
+      ) : (
+        <>
+          <h2 className="text-base font-semibold text-primary mb-1">{t("waiting.title")}</h2>
+          <p className="text-xs text-dim">{t("waiting.desc")}</p>
+        </>
+      )}
 
       {autoLockAgent && (
         <div className="mt-6 px-4 py-2 bg-card rounded-md text-center">
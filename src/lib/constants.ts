@@ -29,6 +29,12 @@ export const RANK_TIERS: Record<number, [string, string]> = {
 
 export const PARTY_COLORS = ["#ff4655", "#00d4aa", "#ecb22e", "#bd3fff"];
 
+// "Group-2" / "Grup-2" -> second party color, solo players get none
+export const getPartyColor = (party: string): string | null => {
+  const index = party.startsWith("Grup-") || party.startsWith("Group-") ? parseInt(party.split("-")[1]) - 1 : -1;
+  return index >= 0 ? PARTY_COLORS[index % 4] : null;
+};
+
 
 export const WEAPON_NAMES: Record<string, string> = {
   "63e6c2b6-4a8e-869c-3d4c-e38355226584": "Odin",
@@ -18,6 +18,7 @@ const translations: Record<Locale, Record<string, string>> = {
     "header.reconnect": "Reconnect",
     "header.close": "Close",
     "header.update": "Update available",
+    "header.clickThrough": "Click-through",
 
     // Footer
     "footer.autoLock": "AUTO-LOCK",
@@ -27,6 +28,7 @@ const translations: Record<Locale, Record<string, string>> = {
     // Waiting State
     "waiting.title": "Waiting for Match",
     "waiting.desc": "Start a match to see player data",
+    "waiting.queuing": "In Queue",
 
     // Pregame
     "pregame.allies": "ALLIES",
@@ -36,9 +38,15 @@ const translations: Record<Locale, Record<string, string>> = {
     // Ingame
     "ingame.allies": "ALLIES",
     "ingame.enemies": "ENEMIES",
+    "ingame.players": "PLAYERS",
+    "ingame.observers": "OBSERVERS",
+    "ingame.coaches": "COACHES",
 
     // Player Card
     "player.level": "Lvl",
+    "player.kd": "K/D",
+    "player.wins": "W",
+    "player.recentMatches": "Last {n} matches",
     "player.copied": "Copied!",
     "player.weaponSkins": "Weapon Skins",
     "player.loadoutNotFound": "Loadout not found",
@@ -53,7 +61,25 @@ const translations: Record<Locale, Record<string, string>> = {
     "settings.autoLockDesc": "Agent to auto-lock when match starts",
     "settings.disableAutoLock": "Disable Auto-Lock",
     "settings.language": "Language",
-    "settings.hotkey": "Toggle Hotkey",
+    "settings.hotkey": "Hotkeys",
+    "settings.hotkey.toggle_overlay": "Show / Hide",
+    "settings.hotkey.toggle_click_through": "Click-Through",
+    "settings.hotkey.refresh_state": "Refresh",
+    "settings.hotkey.cycle_panels": "Cycle Panels",
+    "settings.hotkeyUnbound": "Not set",
+    "settings.overlay": "Overlay",
+    "settings.overlay.click_through": "Click-through (mouse goes to the game)",
+    "settings.overlay.show_on_pregame": "Show on agent select",
+    "settings.overlay.hide_on_match_start": "Hide when the match starts",
+    "settings.overlay.hide_on_idle": "Hide when back in the lobby",
+    "settings.overlay.show_hud": "Show enemy HUD during matches",
+    "settings.overlay.opacity": "Opacity",
+    "settings.diagnostics": "Diagnostics",
+    "settings.diagnostics.logLevel": "Log level",
+    "settings.diagnostics.export": "Export diagnostics",
+    "settings.diagnostics.exporting": "Exporting...",
+    "settings.diagnostics.exported": "Saved to the logs folder",
+    "settings.diagnostics.failed": "Export failed",
     "settings.hotkeyDesc": "Press any key to set new hotkey",
     "settings.hotkeyRecording": "Press a key...",
     "settings.madeBy": "Made by",
@@ -67,6 +93,7 @@ const translations: Record<Locale, Record<string, string>> = {
     "header.reconnect": "Yeniden Bağlan",
     "header.close": "Kapat",
     "header.update": "Güncelleme mevcut",
+    "header.clickThrough": "Tıklama geçirme",
 
     // Footer
     "footer.autoLock": "OTO-KİLİT",
@@ -76,6 +103,7 @@ const translations: Record<Locale, Record<string, string>> = {
     // Waiting State
     "waiting.title": "Maç Bekleniyor",
     "waiting.desc": "Oyuncu verilerini görmek için maç başlatın",
+    "waiting.queuing": "Sırada",
 
     // Pregame
     "pregame.allies": "TAKIMIM",
@@ -85,9 +113,15 @@ const translations: Record<Locale, Record<string, string>> = {
     // Ingame
     "ingame.allies": "TAKIMIM",
     "ingame.enemies": "DÜŞMANLAR",
+    "ingame.players": "OYUNCULAR",
+    "ingame.observers": "İZLEYİCİLER",
+    "ingame.coaches": "KOÇLAR",
 
     // Player Card
     "player.level": "Svye",
+    "player.kd": "K/D",
+    "player.wins": "G",
+    "player.recentMatches": "Son {n} maç",
     "player.copied": "Kopyalandı!",
     "player.weaponSkins": "Silah Skinleri",
     "player.loadoutNotFound": "Loadout bulunamadı",
@@ -102,7 +136,25 @@ const translations: Record<Locale, Record<string, string>> = {
     "settings.autoLockDesc": "Maç başladığında otomatik kilitlenecek ajan",
     "settings.disableAutoLock": "Auto-Lock Kapat",
     "settings.language": "Dil",
-    "settings.hotkey": "Açma/Kapama Tuşu",
+    "settings.hotkey": "Kısayol Tuşları",
+    "settings.hotkey.toggle_overlay": "Göster / Gizle",
+    "settings.hotkey.toggle_click_through": "Tıklama Geçirme",
+    "settings.hotkey.refresh_state": "Yenile",
+    "settings.hotkey.cycle_panels": "Paneller Arası Geçiş",
+    "settings.hotkeyUnbound": "Atanmadı",
+    "settings.overlay": "Overlay",
+    "settings.overlay.click_through": "Tıklama geçirme (fare oyuna gider)",
+    "settings.overlay.show_on_pregame": "Ajan seçiminde göster",
+    "settings.overlay.hide_on_match_start": "Maç başlayınca gizle",
+    "settings.overlay.hide_on_idle": "Lobiye dönünce gizle",
+    "settings.overlay.show_hud": "Maç sırasında rakip HUD'unu göster",
+    "settings.overlay.opacity": "Opaklık",
+    "settings.diagnostics": "Tanılama",
+    "settings.diagnostics.logLevel": "Log seviyesi",
+    "settings.diagnostics.export": "Tanılama verilerini dışa aktar",
+    "settings.diagnostics.exporting": "Dışa aktarılıyor...",
+    "settings.diagnostics.exported": "Log klasörüne kaydedildi",
+    "settings.diagnostics.failed": "Dışa aktarma başarısız",
     "settings.hotkeyDesc": "Yeni tuş atamak için bir tuşa basın",
     "settings.hotkeyRecording": "Bir tuşa basın...",
     "settings.madeBy": "Yapımcı",
@@ -14,14 +14,105 @@ export interface PlayerData {
   rank_tier: number;
   rank_rr: number;
   level: number;
+  stats: PlayerStats | null;
 }
 
 export interface GameState {
-  state: "idle" | "pregame" | "ingame" | "disconnected";
+  state: "idle" | "queuing" | "pregame" | "ingame" | "disconnected";
   match_id: string | null;
   map_name: string | null;
   mode_name: string | null;
   side: string | null;
   allies: PlayerData[];
   enemies: PlayerData[];
+  queue_elapsed_secs: number | null;
+  score_ally: number | null;
+  score_enemy: number | null;
+  round: number | null;
+  queue_id: string | null;
+  server: ServerInfo | null;
+  is_ranked: boolean;
+  is_custom: boolean;
+  is_range: boolean;
+  layout: "teams" | "free_for_all";
+  players: PlayerData[];
+  observers: PlayerData[];
+  coaches: PlayerData[];
+}
+
+export interface ServerInfo {
+  pod_id: string;
+  location: string;
+  region: string;
+  data_center: string;
+  provider: string;
+}
+
+export interface ServerStats {
+  location: string;
+  region: string;
+  data_center: string;
+  matches: number;
+  last_seen: string;
+}
+
+export interface RankInfo {
+  tier: number;
+  rr: number;
+}
+
+// Totals over a player's last few matches
+export interface PlayerStats {
+  matches: number;
+  wins: number;
+  kills: number;
+  deaths: number;
+  assists: number;
+}
+
+export type Enrichment =
+  | { kind: "names"; names: Record<string, string> }
+  | { kind: "parties"; parties: Record<string, string> }
+  | { kind: "ranks"; ranks: Record<string, RankInfo> }
+  | { kind: "stats"; stats: Record<string, PlayerStats> }
+  | { kind: "loadouts" };
+
+export type EnrichmentEvent = Enrichment & { match_id: string };
+
+export interface WindowPosition {
+  x: number;
+  y: number;
+}
+
+export type HotkeyAction = "toggle_overlay" | "toggle_click_through" | "refresh_state" | "cycle_panels";
+
+export type Hotkeys = Record<HotkeyAction, string | null>;
+
+export interface HotkeyError {
+  action: HotkeyAction;
+  binding: string;
+  kind: "invalid" | "conflict" | "unavailable";
+  message: string;
+}
+
+export interface OverlaySettings {
+  show_on_pregame: boolean;
+  hide_on_match_start: boolean;
+  hide_on_idle: boolean;
+  show_hud: boolean;
+  opacity: number;
+}
+
+export type LogLevel = "error" | "warn" | "info" | "debug" | "trace";
+
+// Persisted by the backend in the app config dir
+export interface Settings {
+  version: number;
+  auto_lock_agent: string | null;
+  auto_lock_enabled: boolean;
+  hotkeys: Hotkeys;
+  overlay: OverlaySettings;
+  window_position: WindowPosition | null;
+  hud_position: WindowPosition | null;
+  log_level: LogLevel;
 }
@@ -1,10 +1,13 @@
 import React from "react";
 import ReactDOM from "react-dom/client";
+import { getCurrentWindow } from "@tauri-apps/api/window";
 import App from "./App";
+import Hud from "./Hud";
 import "./index.css";
 
 ReactDOM.createRoot(document.getElementById("root") as HTMLElement).render(
   <React.StrictMode>
-    <App />
+    {/* The backend creates the "hud" window with the same frontend */}
+    {getCurrentWindow().label === "hud" ? <Hud /> : <App />}
   </React.StrictMode>
 );
@@ -1,7 +1,6 @@
 import { create } from "zustand";
-import { persist } from "zustand/middleware";
 import { invoke } from "@tauri-apps/api/core";
-import type { ConnectionStatus, GameState } from "../lib/types";
+import type { ConnectionStatus, EnrichmentEvent, GameState, PlayerData } from "../lib/types";
 
 interface GameStore {
   connected: boolean;
@@ -14,6 +13,7 @@ interface GameStore {
   fetchGameState: () => Promise<void>;
   reconnect: () => Promise<void>;
   setAutoLock: (agent: string | null) => void;
+  applyEnrichment: (event: EnrichmentEvent) => void;
 }
 
 const initialGameState: GameState = {
@@ -24,82 +24,117 @@ const initialGameState: GameState = {
   side: null,
   allies: [],
   enemies: [],
+  queue_elapsed_secs: null,
+  score_ally: null,
+  score_enemy: null,
+  round: null,
+  queue_id: null,
+  server: null,
+  is_ranked: false,
+  is_custom: false,
+  is_range: false,
+  layout: "teams",
+  players: [],
+  observers: [],
+  coaches: [],
 };
 
-export const useGameStore = create<GameStore>()(
-  persist(
-    (set, get) => ({
-      connected: false,
-      region: "",
-      gameState: initialGameState,
-      autoLockAgent: null,
-      consecutiveErrors: 0,
+// Merge a background lookup into one player of the roster
+const enrichPlayer = (player: PlayerData, event: EnrichmentEvent): PlayerData => {
+  switch (event.kind) {
+    case "names":
+      return event.names[player.puuid] ? { ...player, name: event.names[player.puuid] } : player;
+    case "parties":
+      return event.parties[player.puuid] ? { ...player, party: event.parties[player.puuid] } : player;
+    case "ranks": {
+      const rank = event.ranks[player.puuid];
+      if (!rank) return player;
+      return { ...player, rank_tier: player.rank_tier > 0 ? player.rank_tier : rank.tier, rank_rr: rank.rr };
+    }
+    case "stats":
+      return event.stats[player.puuid] ? { ...player, stats: event.stats[player.puuid] } : player;
+    default:
+      return player;
+  }
+};
 
-      initialize: async () => {
-        try {
-          const status = await invoke<ConnectionStatus>("initialize");
-          set({ connected: status.connected, region: status.region, consecutiveErrors: 0 });
+export const useGameStore = create<GameStore>()((set, get) => ({
+  connected: false,
+  region: "",
+  gameState: initialGameState,
+  autoLockAgent: null,
+  consecutiveErrors: 0,
 
-          // Restore auto-lock agent to backend
-          const { autoLockAgent } = get();
-          if (autoLockAgent) {
-            invoke("set_auto_lock", { agent: autoLockAgent });
-          }
-        } catch {
-          set({ connected: false });
-          setTimeout(() => get().initialize(), 5000);
-        }
-      },
+  initialize: async () => {
+    try {
+      const status = await invoke<ConnectionStatus>("initialize");
+      set({ connected: status.connected, region: status.region, consecutiveErrors: 0 });
+    } catch {
+      set({ connected: false });
+      setTimeout(() => get().initialize(), 5000);
+    }
+  },
 
-      reconnect: async () => {
-        set({ connected: false, gameState: initialGameState });
-        await get().initialize();
-        if (get().connected) {
-          await get().fetchGameState();
-        }
-      },
+  reconnect: async () => {
+    set({ connected: false, gameState: initialGameState });
+    await get().initialize();
+    if (get().connected) {
+      await get().fetchGameState();
+    }
+  },
 
-      fetchGameState: async () => {
-        try {
-          const state = await invoke<GameState>("get_game_state");
+  fetchGameState: async () => {
+    try {
+      const state = await invoke<GameState>("get_game_state");
 
-          // Check if disconnected state returned
-          if (state.state === "disconnected") {
-            const errors = get().consecutiveErrors + 1;
-            set({ consecutiveErrors: errors });
+      // Check if disconnected state returned
+      if (state.state === "disconnected") {
+        const errors = get().consecutiveErrors + 1;
+        set({ consecutiveErrors: errors });
 
-            // After 3 consecutive disconnected states, try to reconnect
-            if (errors >= 3) {
-              console.log("Connection lost, attempting reconnect...");
-              await get().reconnect();
-            }
-            return;
-          }
+        // After 3 consecutive disconnected states, try to reconnect
+        if (errors >= 3) {
+          console.log("Connection lost, attempting reconnect...");
+          await get().reconnect();
+        }
+        return;
+      }
 
-          // Success - reset error counter
-          set({ gameState: state, consecutiveErrors: 0, connected: true });
-        } catch {
-          const errors = get().consecutiveErrors + 1;
-          set({ consecutiveErrors: errors });
+      // Success - reset error counter
+      set({ gameState: state, consecutiveErrors: 0, connected: true });
+    } catch {
+      const errors = get().consecutiveErrors + 1;
+      set({ consecutiveErrors: errors });
 
-          // After 3 consecutive errors, try to reconnect
-          if (errors >= 3) {
-            console.log("API errors, attempting reconnect...");
-            await get().reconnect();
-          }
-        }
-      },
+      // After 3 consecutive errors, try to reconnect
+      if (errors >= 3) {
+        console.log("API errors, attempting reconnect...");
+        await get().reconnect();
+      }
+    }
+  },
 
-      setAutoLock: (agent) => {
-        set({ autoLockAgent: agent });
-        invoke("set_auto_lock", { agent });
+  // Persisted by the backend, which echoes it back through "settings-changed"
+  setAutoLock: (agent) => {
+    set({ autoLockAgent: agent });
+    invoke("set_auto_lock", { agent });
+  },
+
+  applyEnrichment: (event) => {
+    const { gameState } = get();
+    // Ignore lookups that finished after the match changed
+    if (gameState.match_id !== event.match_id) return;
+
+    const enrich = (players: PlayerData[]) => players.map((p) => enrichPlayer(p, event));
+    set({
+      gameState: {
+        ...gameState,
+        allies: enrich(gameState.allies),
+        enemies: enrich(gameState.enemies),
+        players: enrich(gameState.players),
+        observers: enrich(gameState.observers),
+        coaches: enrich(gameState.coaches),
       },
-    }),
-    {
-      name: "valorant-tracker-game",
-      partialize: (state) => ({
-        autoLockAgent: state.autoLockAgent,
-      }),
-    }
-  )
-);
+    });
+  },
+}));
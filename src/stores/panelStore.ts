@@ -18,11 +18,14 @@ interface PanelStore {
   isOpen: boolean;
   panelType: PanelType;
   selectedPlayer: PlayerData | null;
+  // Last opened player, kept after closing so the hotkey can bring it back
+  lastPlayer: PlayerData | null;
   hoveredWeapon: HoveredWeapon | null;
 
   openSettings: () => Promise<void>;
   openPlayer: (player: PlayerData) => Promise<void>;
   close: () => Promise<void>;
+  cyclePanel: () => Promise<void>;
   setHoveredWeapon: (weapon: HoveredWeapon | null) => void;
 }
 
@@ -40,6 +43,7 @@ export const usePanelStore = create<PanelStore>((set, get) => ({
   isOpen: false,
   panelType: null,
   selectedPlayer: null,
+  lastPlayer: null,
   hoveredWeapon: null,
 
   openSettings: async () => {
@@ -52,7 +56,7 @@ export const usePanelStore = create<PanelStore>((set, get) => ({
 
   openPlayer: async (player) => {
     const wasOpen = get().isOpen;
-    set({ isOpen: true, panelType: "player", selectedPlayer: player, hoveredWeapon: null });
+    set({ isOpen: true, panelType: "player", selectedPlayer: player, lastPlayer: player, hoveredWeapon: null });
     if (!wasOpen) {
       await resizeWindow(true);
     }
@@ -63,6 +67,18 @@ export const usePanelStore = create<PanelStore>((set, get) => ({
     await resizeWindow(false);
   },
 
+  // Hotkey: closed -> settings -> last player (if any) -> closed
+  cyclePanel: async () => {
+    const { panelType, lastPlayer } = get();
+    if (panelType === null) {
+      await get().openSettings();
+    } else if (panelType === "settings" && lastPlayer) {
+      await get().openPlayer(lastPlayer);
+    } else {
+      await get().close();
+    }
+  },
+
   setHoveredWeapon: (weapon) => {
     set({ hoveredWeapon: weapon });
   },
@@ -1,152 +1,172 @@
 import { create } from "zustand";
-import { persist } from "zustand/middleware";
-import { register, unregister } from "@tauri-apps/plugin-global-shortcut";
-import { getCurrentWindow, PhysicalPosition } from "@tauri-apps/api/window";
+import { invoke } from "@tauri-apps/api/core";
+import type { HotkeyAction, HotkeyError, Hotkeys, LogLevel, OverlaySettings, Settings } from "../lib/types";
+import { useGameStore } from "./gameStore";
 
-interface WindowPosition {
-  x: number;
-  y: number;
-}
-
-interface SettingsState {
-  hotkey: string;
-  windowPosition: WindowPosition | null;
-}
-
-interface SettingsStore extends SettingsState {
+interface SettingsStore {
+  hotkeys: Hotkeys;
+  hotkeyErrors: HotkeyError[];
   isHotkeyPaused: boolean;
-  setHotkey: (key: string) => Promise<boolean>;
-  setWindowPosition: (pos: WindowPosition) => void;
-  registerHotkey: () => Promise<void>;
+  overlay: OverlaySettings;
+  clickThrough: boolean;
+  logLevel: LogLevel;
+  loadSettings: () => Promise<void>;
+  applySettings: (settings: Settings) => void;
+  addHotkeyError: (error: HotkeyError) => void;
+  setHotkey: (action: HotkeyAction, key: string | null) => Promise<boolean>;
   pauseHotkey: () => Promise<void>;
   resumeHotkey: () => Promise<void>;
-  restoreWindowPosition: () => Promise<void>;
-  saveCurrentPosition: () => Promise<void>;
+  setOverlay: (patch: Partial<OverlaySettings>) => Promise<void>;
+  setClickThrough: (enabled: boolean) => Promise<void>;
+  setLogLevel: (level: LogLevel) => Promise<void>;
+  exportDiagnostics: () => Promise<string | null>;
 }
 
-let isToggling = false;
+const DEFAULT_HOTKEYS: Hotkeys = {
+  toggle_overlay: "F2",
+  toggle_click_through: "Ctrl+F2",
+  refresh_state: null,
+  cycle_panels: null,
+};
 
-const toggleWindow = async () => {
-  if (isToggling) return;
-  isToggling = true;
+const DEFAULT_OVERLAY: OverlaySettings = {
+  show_on_pregame: true,
+  hide_on_match_start: false,
+  hide_on_idle: false,
+  show_hud: true,
+  opacity: 1,
+};
 
-  const win = getCurrentWindow();
-  const visible = await win.isVisible();
+// Keys of the old zustand-persisted stores, imported into the backend once
+const LEGACY_SETTINGS_KEY = "valorant-tracker-settings";
+const LEGACY_GAME_KEY = "valorant-tracker-game";
 
-  if (visible) {
-    await win.hide();
-  } else {
-    await win.show();
-    await win.setFocus();
+const readLegacy = (key: string): Record<string, unknown> => {
+  try {
+    return JSON.parse(localStorage.getItem(key) || "{}").state || {};
+  } catch {
+    return {};
   }
+};
 
-  setTimeout(() => {
-    isToggling = false;
-  }, 300);
+const importLegacySettings = async () => {
+  if (!localStorage.getItem(LEGACY_SETTINGS_KEY) && !localStorage.getItem(LEGACY_GAME_KEY)) return;
+
+  const legacy = { ...readLegacy(LEGACY_SETTINGS_KEY), ...readLegacy(LEGACY_GAME_KEY) };
+  const patch: Record<string, unknown> = {};
+  if (legacy.hotkey) patch.hotkeys = { ...DEFAULT_HOTKEYS, toggle_overlay: legacy.hotkey };
+  if (legacy.windowPosition) patch.window_position = legacy.windowPosition;
+  if (legacy.autoLockAgent) patch.auto_lock_agent = legacy.autoLockAgent;
+
+  try {
+    await invoke("update_settings", { patch });
+    localStorage.removeItem(LEGACY_SETTINGS_KEY);
+    localStorage.removeItem(LEGACY_GAME_KEY);
+  } catch (error) {
+    console.error("Failed to import legacy settings:", error);
+  }
 };
 
-export const useSettingsStore = create<SettingsStore>()(
-  persist(
-    (set, get) => ({
-      hotkey: "F2",
-      windowPosition: null,
-      isHotkeyPaused: false,
-
-      setHotkey: async (newKey: string) => {
-        const currentKey = get().hotkey;
-
-        // If same key, just resume
-        if (newKey === currentKey) {
-          await get().resumeHotkey();
-          return true;
-        }
-
-        try {
-          // Unregister old hotkey first
-          try {
-            await unregister(currentKey);
-          } catch {}
-
-          // Register new hotkey
-          await register(newKey, toggleWindow);
-          set({ hotkey: newKey, isHotkeyPaused: false });
-          return true;
-        } catch (error) {
-          console.error("Failed to register hotkey:", error);
-          // Restore old hotkey
-          try {
-            await register(currentKey, toggleWindow);
-          } catch {}
-          set({ isHotkeyPaused: false });
-          return false;
-        }
-      },
-
-      setWindowPosition: (pos: WindowPosition) => {
-        set({ windowPosition: pos });
-      },
-
-      registerHotkey: async () => {
-        const { hotkey } = get();
-        try {
-          await register(hotkey, toggleWindow);
-        } catch (error) {
-          console.error("Failed to register hotkey:", error);
-        }
-      },
-
-      pauseHotkey: async () => {
-        const { hotkey, isHotkeyPaused } = get();
-        if (isHotkeyPaused) return;
-
-        try {
-          await unregister(hotkey);
-          set({ isHotkeyPaused: true });
-        } catch (error) {
-          console.error("Failed to pause hotkey:", error);
-        }
-      },
-
-      resumeHotkey: async () => {
-        const { hotkey, isHotkeyPaused } = get();
-        if (!isHotkeyPaused) return;
-
-        try {
-          await register(hotkey, toggleWindow);
-          set({ isHotkeyPaused: false });
-        } catch (error) {
-          console.error("Failed to resume hotkey:", error);
-        }
-      },
-
-      restoreWindowPosition: async () => {
-        const { windowPosition } = get();
-        if (windowPosition) {
-          try {
-            const win = getCurrentWindow();
-            await win.setPosition(new PhysicalPosition(windowPosition.x, windowPosition.y));
-          } catch (error) {
-            console.error("Failed to restore window position:", error);
-          }
-        }
-      },
-
-      saveCurrentPosition: async () => {
-        try {
-          const win = getCurrentWindow();
-          const pos = await win.outerPosition();
-          set({ windowPosition: { x: pos.x, y: pos.y } });
-        } catch (error) {
-          console.error("Failed to save window position:", error);
-        }
-      },
-    }),
-    {
-      name: "valorant-tracker-settings",
-      partialize: (state): SettingsState => ({
-        hotkey: state.hotkey,
-        windowPosition: state.windowPosition,
-      }),
+export const useSettingsStore = create<SettingsStore>()((set, get) => ({
+  hotkeys: DEFAULT_HOTKEYS,
+  hotkeyErrors: [],
+  isHotkeyPaused: false,
+  overlay: DEFAULT_OVERLAY,
+  clickThrough: false,
+  logLevel: "info",
+
+  loadSettings: async () => {
+    await importLegacySettings();
+    try {
+      get().applySettings(await invoke<Settings>("get_settings"));
+      set({
+        hotkeyErrors: await invoke<HotkeyError[]>("get_hotkey_errors"),
+        clickThrough: await invoke<boolean>("get_click_through"),
+      });
+    } catch (error) {
+      console.error("Failed to load settings:", error);
+    }
+  },
+
+  applySettings: (settings) => {
+    set({ hotkeys: settings.hotkeys, overlay: settings.overlay, logLevel: settings.log_level });
+    // Switched off from the tray - show it as cleared until an agent is picked again
+    useGameStore.setState({ autoLockAgent: settings.auto_lock_enabled ? settings.auto_lock_agent : null });
+  },
+
+  addHotkeyError: (error) => {
+    set((s) => ({ hotkeyErrors: [...s.hotkeyErrors.filter((e) => e.action !== error.action), error] }));
+  },
+
+  // Registration happens in the backend, which keeps the old key if the new one fails
+  setHotkey: async (action, key) => {
+    try {
+      get().applySettings(await invoke<Settings>("set_hotkey", { action, binding: key }));
+      set((s) => ({ isHotkeyPaused: false, hotkeyErrors: s.hotkeyErrors.filter((e) => e.action !== action) }));
+      return true;
+    } catch (error) {
+      console.error("Failed to register hotkey:", error);
+      set({ isHotkeyPaused: false });
+      return false;
+    }
+  },
+
+  pauseHotkey: async () => {
+    if (get().isHotkeyPaused) return;
+
+    try {
+      await invoke("pause_hotkeys");
+      set({ isHotkeyPaused: true });
+    } catch (error) {
+      console.error("Failed to pause hotkey:", error);
+    }
+  },
+
+  resumeHotkey: async () => {
+    if (!get().isHotkeyPaused) return;
+
+    try {
+      await invoke("resume_hotkeys");
+      set({ isHotkeyPaused: false });
+    } catch (error) {
+      console.error("Failed to resume hotkey:", error);
+    }
+  },
+
+  // Show/hide rules run in the backend on phase changes, opacity is applied in App
+  setOverlay: async (patch) => {
+    try {
+      get().applySettings(await invoke<Settings>("set_overlay_settings", { overlay: { ...get().overlay, ...patch } }));
+    } catch (error) {
+      console.error("Failed to update overlay settings:", error);
+    }
+  },
+
+  setClickThrough: async (enabled) => {
+    try {
+      await invoke("set_click_through", { enabled });
+      set({ clickThrough: enabled });
+    } catch (error) {
+      console.error("Failed to set click-through:", error);
+    }
+  },
+
+  // Applied by the backend right away, no restart needed
+  setLogLevel: async (level) => {
+    try {
+      get().applySettings(await invoke<Settings>("update_settings", { patch: { log_level: level } }));
+    } catch (error) {
+      console.error("Failed to set log level:", error);
+    }
+  },
+
+  // Returns the zip path, the backend also reveals it in the file manager
+  exportDiagnostics: async () => {
+    try {
+      return await invoke<string>("export_diagnostics");
+    } catch (error) {
+      console.error("Failed to export diagnostics:", error);
+      return null;
     }
-  )
-);
+  },
+}));